@@ -0,0 +1,65 @@
+/// format unix timestamp in milliseconds as RFC 3339 utc date time
+/// as required by `issuanceDate` of verifiable credential
+pub fn rfc3339_from_millis(millis: u64) -> String {
+    let seconds = millis / 1000;
+    let (days, seconds_of_day) = (seconds / 86_400, seconds % 86_400);
+    let (year, month, day) = civil_from_days(days as i64);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    )
+}
+
+/// convert days since unix epoch into (year, month, day) of proleptic gregorian calendar
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rfc3339_from_millis;
+
+    #[test]
+    fn formats_unix_epoch() {
+        assert_eq!(rfc3339_from_millis(0), "1970-01-01T00:00:00Z");
+    }
+
+    #[test]
+    fn drops_milliseconds() {
+        assert_eq!(rfc3339_from_millis(1_999), "1970-01-01T00:00:01Z");
+    }
+
+    #[test]
+    fn formats_leap_days() {
+        assert_eq!(rfc3339_from_millis(951_782_400_000), "2000-02-29T00:00:00Z");
+        assert_eq!(rfc3339_from_millis(1_709_164_800_000), "2024-02-29T00:00:00Z");
+    }
+
+    #[test]
+    fn formats_time_of_day() {
+        assert_eq!(rfc3339_from_millis(1_700_000_000_000), "2023-11-14T22:13:20Z");
+        assert_eq!(rfc3339_from_millis(1_704_067_199_000), "2023-12-31T23:59:59Z");
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+
+use types::{certificate::CertificateStatus, validator::ValidatorId};
+
+/// Reason a credential could not be verified
+#[derive(Debug, Clone, PartialEq)]
+pub enum CredentialError {
+    /// credential is not a well formed edu-chain certificate credential
+    Malformed(String),
+    /// chain state could not be queried
+    Source(String),
+    /// block the credential is anchored to is not part of the chain
+    UnknownAnchor,
+    /// dates in credential are not the timestamps of the blocks they refer to
+    DateMismatch,
+    /// certificate does not exists on chain
+    NoCertificate,
+    /// issuer in credential is not the university that issued the certificate
+    IssuerMismatch,
+    /// subject in credential is not the holder of the certificate
+    HolderMismatch,
+    /// document hash in credential is not the one recorded on chain
+    DocumentMismatch,
+    /// certificate is no longer active
+    NotActive(CertificateStatus),
+    /// attested validator does not validate the issuer anymore
+    NotAttested(ValidatorId),
+}
+
+impl fmt::Display for CredentialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialError::Malformed(reason) => write!(f, "malformed credential: {}", reason),
+            CredentialError::Source(reason) => write!(f, "unable to query chain: {}", reason),
+            CredentialError::UnknownAnchor => f.write_str("anchor block is not part of the chain"),
+            CredentialError::DateMismatch => f.write_str("dates do not match chain record"),
+            CredentialError::NoCertificate => f.write_str("certificate does not exists on chain"),
+            CredentialError::IssuerMismatch => f.write_str("issuer does not match chain record"),
+            CredentialError::HolderMismatch => f.write_str("holder does not match chain record"),
+            CredentialError::DocumentMismatch => {
+                f.write_str("document hash does not match chain record")
+            }
+            CredentialError::NotActive(status) => write!(f, "certificate is {:?}", status),
+            CredentialError::NotAttested(validator) => {
+                write!(f, "issuer is not validated by {}", validator)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CredentialError {}
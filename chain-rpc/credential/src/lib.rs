@@ -0,0 +1,125 @@
+//! W3C Verifiable Credential representation of on-chain certificates
+//!
+//! `render` turns a certificate recorded in `pallet_university` into a VC JSON-LD document
+//! whose proof anchors it to the on-chain record and block hash.
+//! `verify` checks a presented VC against chain state.
+
+mod date;
+mod error;
+mod render;
+mod verify;
+
+pub use error::CredentialError;
+pub use render::{render, AnchorBlock};
+pub use verify::{verify, CertificateSource};
+
+use serde::{Deserialize, Serialize};
+
+/// W3C credentials data model context
+pub const CREDENTIALS_CONTEXT: &str = "https://www.w3.org/2018/credentials/v1";
+/// prefix of every identifier in the credential
+pub const URN_PREFIX: &str = "urn:edu-chain";
+/// type of the credential next to `VerifiableCredential`
+pub const CREDENTIAL_TYPE: &str = "EduChainCertificate";
+/// type of the proof anchoring credential to chain
+pub const PROOF_TYPE: &str = "EduChainAnchorProof";
+
+/// Verifiable credential of a certificate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiableCredential {
+    #[serde(rename = "@context")]
+    pub context: Vec<serde_json::Value>,
+    /// urn of the certificate
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+    pub issuer: Issuer,
+    pub issuance_date: String,
+    pub credential_subject: CredentialSubject,
+    pub credential_status: CredentialStatus,
+    /// validators that have validated the issuing university
+    #[serde(default)]
+    pub evidence: Vec<Attestation>,
+    pub proof: AnchorProof,
+}
+
+/// University issuing the certificate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Issuer {
+    /// urn of the university
+    pub id: String,
+    /// account administering the university
+    pub admin: String,
+    /// ipfs link to the permanent information of university
+    pub permanent_info: String,
+}
+
+/// Student holding the certificate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialSubject {
+    /// urn of the student
+    pub id: String,
+    /// urn of the programme certificate is awarded for
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub programme: Option<String>,
+    /// ipfs link to certificate document
+    pub document: String,
+    /// hex encoded blake2_256 hash of certificate document
+    pub document_hash: String,
+}
+
+/// Status of certificate when the credential was rendered
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialStatus {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub status: types::certificate::CertificateStatus,
+}
+
+/// Validation of the issuing university by a validator of `pallet_checked_validation`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Attestation {
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+    /// urn of the validator
+    pub validator: String,
+}
+
+/// Proof that points to the on-chain record of the certificate
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AnchorProof {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub created: String,
+    pub proof_purpose: String,
+    /// urn of the issuing university
+    pub verification_method: String,
+    /// storage item holding the certificate
+    pub record: String,
+    /// hex encoded hash of block the credential was rendered at
+    pub block_hash: String,
+    pub block_number: u64,
+}
+
+/// urn of an entity of kind `kind`
+pub fn urn(kind: &str, id: &types::UniqId) -> String {
+    format!("{}:{}:{}", URN_PREFIX, kind, id)
+}
+
+/// parse the id back from urn of kind `kind`
+pub fn parse_urn(kind: &str, urn: &str) -> Result<types::UniqId, CredentialError> {
+    urn.strip_prefix(URN_PREFIX)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .and_then(|rest| rest.strip_prefix(kind))
+        .and_then(|rest| rest.strip_prefix(':'))
+        .ok_or_else(|| CredentialError::Malformed(format!("not a {} urn: {}", kind, urn)))?
+        .parse()
+        .map_err(|e| CredentialError::Malformed(format!("invalid id in {}: {}", urn, e)))
+}
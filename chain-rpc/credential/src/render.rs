@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use serde_json::json;
+use sp_core::{bytes::to_hex, H256};
+use types::{
+    certificate::{Certificate, CertificateId},
+    university::University,
+    validator::ValidatorId,
+    StdIpfsLink,
+};
+
+use crate::{
+    date::rfc3339_from_millis, urn, AnchorProof, Attestation, CredentialStatus, CredentialSubject,
+    Issuer, VerifiableCredential, CREDENTIALS_CONTEXT, CREDENTIAL_TYPE, PROOF_TYPE, URN_PREFIX,
+};
+
+/// Block the certificate record have been read at
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnchorBlock {
+    pub hash: H256,
+    pub number: u64,
+    /// timestamp of the block in milliseconds
+    pub timestamp_millis: u64,
+}
+
+/// render the certificate issued by `university` as verifiable credential
+///
+/// `issued_at_millis` is the timestamp of block the certificate was issued in
+/// and `attestations` are the validators that have validated the issuing university
+pub fn render<AccountId: Display, BlockNumber>(
+    certificate_id: &CertificateId,
+    certificate: &Certificate<BlockNumber>,
+    university: &University<AccountId>,
+    issued_at_millis: u64,
+    attestations: &[ValidatorId],
+    anchor: &AnchorBlock,
+) -> VerifiableCredential {
+    let id = urn("certificate", certificate_id);
+    let issuer_id = urn("university", &certificate.university);
+
+    VerifiableCredential {
+        context: vec![
+            json!(CREDENTIALS_CONTEXT),
+            json!({ "@vocab": format!("{}:vocab#", URN_PREFIX) }),
+        ],
+        id: id.clone(),
+        kind: vec!["VerifiableCredential".into(), CREDENTIAL_TYPE.into()],
+        issuer: Issuer {
+            id: issuer_id.clone(),
+            admin: university.admin.to_string(),
+            permanent_info: ipfs_link(&university.permanent_info),
+        },
+        issuance_date: rfc3339_from_millis(issued_at_millis),
+        credential_subject: CredentialSubject {
+            id: urn("student", &certificate.student),
+            programme: certificate
+                .programme
+                .as_ref()
+                .map(|programme| urn("programme", programme)),
+            document: ipfs_link(&certificate.document),
+            document_hash: to_hex(&certificate.document_hash, false),
+        },
+        credential_status: CredentialStatus {
+            id: format!("{}#status", id),
+            kind: format!("{}Status", CREDENTIAL_TYPE),
+            status: certificate.status.clone(),
+        },
+        evidence: attestations
+            .iter()
+            .map(|validator| Attestation {
+                kind: vec!["EduChainValidation".into()],
+                validator: urn("validator", validator),
+            })
+            .collect(),
+        proof: AnchorProof {
+            kind: PROOF_TYPE.into(),
+            created: rfc3339_from_millis(anchor.timestamp_millis),
+            proof_purpose: "assertionMethod".into(),
+            verification_method: issuer_id,
+            record: format!("University.Certificates/{}", certificate_id),
+            block_hash: format!("{:?}", anchor.hash),
+            block_number: anchor.number,
+        },
+    }
+}
+
+fn ipfs_link(link: &StdIpfsLink) -> String {
+    String::from_utf8_lossy(link.get()).into_owned()
+}
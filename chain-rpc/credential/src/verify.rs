@@ -0,0 +1,297 @@
+use sp_core::{bytes::from_hex, H256};
+use types::{
+    certificate::{CertificateId, CertificateStatus, CertificateVerification, DocumentHash},
+    validator::ValidatorId,
+};
+
+use crate::{
+    date::rfc3339_from_millis, parse_urn, AnchorBlock, CredentialError, VerifiableCredential,
+    CREDENTIAL_TYPE, PROOF_TYPE,
+};
+
+/// Read access to the certificates on chain
+///
+/// matches the `certificate_verify` runtime api
+/// so it can be implemented on top of the runtime api or the rpc client
+pub trait CertificateSource {
+    type BlockNumber;
+
+    fn certificate_verify(
+        &self,
+        certificate_id: CertificateId,
+        document_hash: DocumentHash,
+        validator: Option<ValidatorId>,
+    ) -> Result<Option<CertificateVerification<Self::BlockNumber>>, String>;
+
+    /// block at height `number` of the canonical chain
+    fn anchor_block(&self, number: u64) -> Result<Option<AnchorBlock>, String>;
+}
+
+/// check the presented credential against chain state
+///
+/// credential is valid if it is anchored to a block of the canonical chain, the certificate
+/// is still active, issuer, holder, document and dates matches the on chain record
+/// and every attested validator still validates the issuer
+pub fn verify<S>(
+    credential: &VerifiableCredential,
+    source: &S,
+) -> Result<CertificateVerification<S::BlockNumber>, CredentialError>
+where
+    S: CertificateSource,
+    S::BlockNumber: Clone + Into<u64>,
+{
+    let malformed = |reason: &str| CredentialError::Malformed(reason.into());
+    if !credential.kind.iter().any(|kind| kind == CREDENTIAL_TYPE) {
+        return Err(malformed("not an edu-chain certificate"));
+    }
+    if credential.proof.kind != PROOF_TYPE {
+        return Err(malformed("unsupported proof type"));
+    }
+
+    let certificate_id = parse_urn("certificate", &credential.id)?;
+    let issuer = parse_urn("university", &credential.issuer.id)?;
+    let holder = parse_urn("student", &credential.credential_subject.id)?;
+    let document_hash: DocumentHash = from_hex(&credential.credential_subject.document_hash)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| malformed("invalid document hash"))?;
+    let block_hash = from_hex(&credential.proof.block_hash)
+        .ok()
+        .filter(|bytes| bytes.len() == H256::len_bytes())
+        .map(|bytes| H256::from_slice(&bytes))
+        .ok_or_else(|| malformed("invalid block hash"))?;
+
+    // credential have to be rendered from a block of this chain
+    let anchor = source
+        .anchor_block(credential.proof.block_number)
+        .map_err(CredentialError::Source)?
+        .ok_or(CredentialError::UnknownAnchor)?;
+    if anchor.hash != block_hash {
+        return Err(CredentialError::UnknownAnchor);
+    }
+    if credential.proof.created != rfc3339_from_millis(anchor.timestamp_millis) {
+        return Err(CredentialError::DateMismatch);
+    }
+
+    let verification = source
+        .certificate_verify(certificate_id, document_hash, None)
+        .map_err(CredentialError::Source)?
+        .ok_or(CredentialError::NoCertificate)?;
+    if verification.issuer != issuer {
+        return Err(CredentialError::IssuerMismatch);
+    }
+    if verification.holder != holder {
+        return Err(CredentialError::HolderMismatch);
+    }
+    if !verification.document_matches {
+        return Err(CredentialError::DocumentMismatch);
+    }
+    if verification.status != CertificateStatus::Active {
+        return Err(CredentialError::NotActive(verification.status));
+    }
+    let issued_in = source
+        .anchor_block(verification.issued_on.clone().into())
+        .map_err(CredentialError::Source)?
+        .ok_or(CredentialError::UnknownAnchor)?;
+    if credential.issuance_date != rfc3339_from_millis(issued_in.timestamp_millis) {
+        return Err(CredentialError::DateMismatch);
+    }
+
+    for attestation in credential.evidence.iter() {
+        let validator = parse_urn("validator", &attestation.validator)?;
+        let validated = source
+            .certificate_verify(certificate_id, document_hash, Some(validator))
+            .map_err(CredentialError::Source)?
+            .and_then(|verification| verification.issuer_validated);
+        if validated != Some(true) {
+            return Err(CredentialError::NotAttested(validator));
+        }
+    }
+
+    Ok(verification)
+}
+
+#[cfg(test)]
+mod tests {
+    use sp_core::H256;
+    use types::{
+        certificate::{Certificate, CertificateStatus, CertificateVerification},
+        university::University,
+        StdIpfsLink, UniqId,
+    };
+
+    use super::*;
+    use crate::render;
+
+    const CERTIFICATE: UniqId = UniqId::new([1; 32]);
+    const UNIVERSITY: UniqId = UniqId::new([2; 32]);
+    const STUDENT: UniqId = UniqId::new([3; 32]);
+    const VALIDATOR: UniqId = UniqId::new([4; 32]);
+    const ISSUED_ON: u32 = 10;
+    /// best block of `MockChain`
+    const BEST_BLOCK: u32 = 20;
+
+    /// block `number` of `MockChain`
+    fn block(number: u64) -> AnchorBlock {
+        AnchorBlock {
+            hash: H256::repeat_byte(number as u8),
+            number,
+            timestamp_millis: 1_600_000_000_000 + number * 6_000,
+        }
+    }
+
+    /// chain holding a single certificate
+    struct MockChain {
+        certificate: Certificate<u32>,
+        validated_by: Vec<ValidatorId>,
+    }
+
+    impl CertificateSource for MockChain {
+        type BlockNumber = u32;
+
+        fn certificate_verify(
+            &self,
+            certificate_id: CertificateId,
+            document_hash: DocumentHash,
+            validator: Option<ValidatorId>,
+        ) -> Result<Option<CertificateVerification<u32>>, String> {
+            let certificate = &self.certificate;
+            Ok((certificate_id == CERTIFICATE).then(|| CertificateVerification {
+                issuer: certificate.university,
+                holder: certificate.student,
+                programme: certificate.programme,
+                issued_on: certificate.issued_on,
+                status: certificate.status.clone(),
+                document_matches: certificate.document_hash == document_hash,
+                issuer_validated: validator
+                    .map(|validator| self.validated_by.contains(&validator)),
+            }))
+        }
+
+        fn anchor_block(&self, number: u64) -> Result<Option<AnchorBlock>, String> {
+            Ok((number <= BEST_BLOCK.into()).then(|| block(number)))
+        }
+    }
+
+    fn chain() -> MockChain {
+        MockChain {
+            certificate: Certificate {
+                university: UNIVERSITY,
+                student: STUDENT,
+                programme: None,
+                issued_on: ISSUED_ON,
+                document: StdIpfsLink::new(Default::default()),
+                document_hash: [7; 32],
+                status: CertificateStatus::Active,
+                reissue_of: None,
+            },
+            validated_by: vec![VALIDATOR],
+        }
+    }
+
+    fn credential(chain: &MockChain) -> VerifiableCredential {
+        let university = University {
+            admin: 42u64,
+            permanent_info: StdIpfsLink::new(Default::default()),
+        };
+        render(
+            &CERTIFICATE,
+            &chain.certificate,
+            &university,
+            block(ISSUED_ON.into()).timestamp_millis,
+            &[VALIDATOR],
+            &block(BEST_BLOCK.into()),
+        )
+    }
+
+    #[test]
+    fn rendered_credential_verifies() {
+        let chain = chain();
+        let json = serde_json::to_string(&credential(&chain)).unwrap();
+        let presented: VerifiableCredential = serde_json::from_str(&json).unwrap();
+        assert_eq!(presented, credential(&chain));
+
+        let verification = verify(&presented, &chain).unwrap();
+        assert_eq!(verification.issuer, UNIVERSITY);
+        assert_eq!(verification.holder, STUDENT);
+        assert_eq!(verification.issued_on, ISSUED_ON);
+    }
+
+    #[test]
+    fn credential_anchored_to_unknown_block_fails() {
+        let chain = chain();
+
+        let mut forked = credential(&chain);
+        forked.proof.block_hash = format!("{:?}", H256::repeat_byte(0xff));
+        assert_eq!(verify(&forked, &chain), Err(CredentialError::UnknownAnchor));
+
+        let mut future = credential(&chain);
+        let ahead = block(u64::from(BEST_BLOCK) + 1);
+        future.proof.block_number = ahead.number;
+        future.proof.block_hash = format!("{:?}", ahead.hash);
+        assert_eq!(verify(&future, &chain), Err(CredentialError::UnknownAnchor));
+
+        let mut garbage = credential(&chain);
+        garbage.proof.block_hash = "0x1234".into();
+        assert!(matches!(
+            verify(&garbage, &chain),
+            Err(CredentialError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn tampered_dates_fail() {
+        let chain = chain();
+        let later = rfc3339_from_millis(block(u64::from(ISSUED_ON) + 1).timestamp_millis);
+
+        let mut backdated = credential(&chain);
+        backdated.issuance_date = later.clone();
+        assert_eq!(
+            verify(&backdated, &chain),
+            Err(CredentialError::DateMismatch)
+        );
+
+        let mut recreated = credential(&chain);
+        recreated.proof.created = later;
+        assert_eq!(
+            verify(&recreated, &chain),
+            Err(CredentialError::DateMismatch)
+        );
+    }
+
+    #[test]
+    fn tampered_document_hash_fails() {
+        let chain = chain();
+        let mut tampered = credential(&chain);
+        tampered.credential_subject.document_hash = format!("{:?}", H256::repeat_byte(8));
+
+        assert_eq!(
+            verify(&tampered, &chain),
+            Err(CredentialError::DocumentMismatch)
+        );
+    }
+
+    #[test]
+    fn revoked_certificate_fails() {
+        let mut chain = chain();
+        let credential = credential(&chain);
+        chain.certificate.status = CertificateStatus::Revoked;
+
+        assert_eq!(
+            verify(&credential, &chain),
+            Err(CredentialError::NotActive(CertificateStatus::Revoked))
+        );
+    }
+
+    #[test]
+    fn withdrawn_attestation_fails() {
+        let mut chain = chain();
+        let credential = credential(&chain);
+        chain.validated_by.clear();
+
+        assert_eq!(
+            verify(&credential, &chain),
+            Err(CredentialError::NotAttested(VALIDATOR))
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use chain_credential::{AnchorBlock, CertificateSource, VerifiableCredential};
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sp_api::{BlockId, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use types::{certificate::*, validator::ValidatorId};
+
+pub use chain_rpc_runtime_api::certificate::PalletCertificateApi as CertificateRuntimeApi;
+
+use crate::error::{credential_error, runtime_error};
+
+#[rpc(client, server)]
+pub trait CertificateApi<BlockHash, BlockNumber> {
+	/// verify the certificate against blake2_256 hash of presented document
+	/// at the given block or at best block
+	#[method(name = "certificate_verify")]
+	fn certificate_verify(
+		&self,
+		certificate_id: CertificateId,
+		document_hash: H256,
+		validator: Option<ValidatorId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CertificateVerification<BlockNumber>>>;
+
+	/// verify the certificate included in a batch with it's merkle proof
+	#[method(name = "certificate_verifyBatched")]
+	fn certificate_verify_batched(
+		&self,
+		batch_id: CertificateBatchId,
+		leaf: CertificateLeaf,
+		proof: Vec<H256>,
+		validator: Option<ValidatorId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CertificateVerification<BlockNumber>>>;
+
+	/// verify a verifiable credential rendered from an on chain certificate
+	/// against the given block or best block
+	#[method(name = "certificate_verifyCredential")]
+	fn certificate_verify_credential(
+		&self,
+		credential: VerifiableCredential,
+		at: Option<BlockHash>,
+	) -> RpcResult<CertificateVerification<BlockNumber>>;
+}
+
+pub struct Certificate<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Certificate<C, P> {
+	/// Creates a new instance of the Certificate Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Chain state as seen from block `at`
+struct ChainSource<'a, C, Block: BlockT, BlockNumber> {
+	client: &'a C,
+	at: Block::Hash,
+	_marker: std::marker::PhantomData<BlockNumber>,
+}
+
+impl<'a, C, Block, BlockNumber> CertificateSource for ChainSource<'a, C, Block, BlockNumber>
+where
+	Block: BlockT<Hash = H256>,
+	BlockNumber: Codec,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: CertificateRuntimeApi<Block, BlockNumber>,
+{
+	type BlockNumber = BlockNumber;
+
+	fn certificate_verify(
+		&self,
+		certificate_id: CertificateId,
+		document_hash: DocumentHash,
+		validator: Option<ValidatorId>,
+	) -> Result<Option<CertificateVerification<BlockNumber>>, String> {
+		self.client
+			.runtime_api()
+			.certificate_verify(&BlockId::Hash(self.at), certificate_id, document_hash, validator)
+			.map_err(|e| e.to_string())
+	}
+
+	fn anchor_block(&self, number: u64) -> Result<Option<AnchorBlock>, String> {
+		let block_number = match NumberFor::<Block>::try_from(number) {
+			Ok(block_number) => block_number,
+			Err(_) => return Ok(None),
+		};
+		let hash = match self.client.hash(block_number).map_err(|e| e.to_string())? {
+			Some(hash) => hash,
+			None => return Ok(None),
+		};
+		let timestamp_millis = self
+			.client
+			.runtime_api()
+			.block_timestamp(&BlockId::Hash(hash))
+			.map_err(|e| e.to_string())?;
+
+		Ok(Some(AnchorBlock { hash, number, timestamp_millis }))
+	}
+}
+
+impl<C, Block, BlockNumber> CertificateApiServer<<Block as BlockT>::Hash, BlockNumber>
+	for Certificate<C, Block>
+where
+	Block: BlockT<Hash = H256>,
+	BlockNumber: Codec + Clone + Into<u64> + Send + Sync + 'static + serde::Serialize,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: CertificateRuntimeApi<Block, BlockNumber>,
+{
+	fn certificate_verify(
+		&self,
+		certificate_id: CertificateId,
+		document_hash: H256,
+		validator: Option<ValidatorId>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<CertificateVerification<BlockNumber>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.certificate_verify(&block_id, certificate_id, document_hash.to_fixed_bytes(), validator)
+			.map_err(|e| runtime_error("Unable to verify certificate.", e))
+	}
+
+	fn certificate_verify_batched(
+		&self,
+		batch_id: CertificateBatchId,
+		leaf: CertificateLeaf,
+		proof: Vec<H256>,
+		validator: Option<ValidatorId>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<CertificateVerification<BlockNumber>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+		let proof = proof.into_iter().map(|node| node.to_fixed_bytes()).collect();
+
+		api.certificate_batch_verify(&block_id, batch_id, leaf, proof, validator)
+			.map_err(|e| runtime_error("Unable to verify batched certificate.", e))
+	}
+
+	fn certificate_verify_credential(
+		&self,
+		credential: VerifiableCredential,
+		at: Option<Block::Hash>,
+	) -> RpcResult<CertificateVerification<BlockNumber>> {
+		let source = ChainSource::<C, Block, BlockNumber> {
+			client: &*self.client,
+			at: at.unwrap_or_else(|| self.client.info().best_hash),
+			_marker: Default::default(),
+		};
+
+		chain_credential::verify(&credential, &source).map_err(credential_error)
+	}
+}
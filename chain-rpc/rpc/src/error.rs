@@ -0,0 +1,24 @@
+use jsonrpsee::{
+	core::Error as JsonRpseeError,
+	types::error::{CallError, ErrorObject},
+};
+
+/// Error code returned when runtime api call fails
+pub const RUNTIME_ERROR: i32 = 1;
+/// Error code returned when presented credential does not verify
+pub const CREDENTIAL_ERROR: i32 = 2;
+
+/// map the error of runtime api call into rpc error
+pub fn runtime_error(message: &'static str, error: impl ToString) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, message, Some(error.to_string()))).into()
+}
+
+/// map the reason credential did not verify into rpc error
+pub fn credential_error(error: chain_credential::CredentialError) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		CREDENTIAL_ERROR,
+		"Credential is not valid.",
+		Some(error.to_string()),
+	))
+	.into()
+}
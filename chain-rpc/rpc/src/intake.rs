@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sp_api::{BlockId, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{traits::Block as BlockT, AccountId32};
+use types::{intake::*, primitives::Page, university::UniversityId};
+
+pub use chain_rpc_runtime_api::intake::PalletIntakeApi as IntakeRuntimeApi;
+
+use crate::error::runtime_error;
+
+#[rpc(client, server)]
+pub trait IntakeApi<BlockHash, BlockNumber> {
+	/// page of intakes announced by `university` starting after intake index `start`
+	#[method(name = "intake_list")]
+	fn intakes(
+		&self,
+		university: UniversityId,
+		start: Option<u32>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<IntakeId<UniversityId>, IntakeInfo<BlockNumber>>>;
+
+	/// page of applications made to `intake` starting after applicant `start`
+	#[method(name = "intake_listApplications")]
+	fn applications(
+		&self,
+		intake: IntakeId<UniversityId>,
+		start: Option<AccountId32>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<AccountId32, IntakeApplication<BlockNumber>>>;
+
+	/// page of students accepted in `intake` starting after student `start`
+	#[method(name = "intake_listAccepted")]
+	fn accepted_applications(
+		&self,
+		intake: IntakeId<UniversityId>,
+		start: Option<AccountId32>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<AccountId32, AdmissionOffer<BlockNumber>>>;
+}
+
+pub struct Intake<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Intake<C, P> {
+	/// Creates a new instance of the Intake Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, BlockNumber> IntakeApiServer<<Block as BlockT>::Hash, BlockNumber>
+	for Intake<C, Block>
+where
+	Block: BlockT,
+	BlockNumber: Codec + Send + Sync + 'static + serde::Serialize,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: IntakeRuntimeApi<Block, BlockNumber>,
+{
+	fn intakes(
+		&self,
+		university: UniversityId,
+		start: Option<u32>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<IntakeId<UniversityId>, IntakeInfo<BlockNumber>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.intakes(&block_id, university, start, limit.unwrap_or(u32::MAX))
+			.map_err(|e| runtime_error("Unable to query intakes.", e))
+	}
+
+	fn applications(
+		&self,
+		intake: IntakeId<UniversityId>,
+		start: Option<AccountId32>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<AccountId32, IntakeApplication<BlockNumber>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.applications(&block_id, intake, start, limit.unwrap_or(u32::MAX))
+			.map_err(|e| runtime_error("Unable to query applications.", e))
+	}
+
+	fn accepted_applications(
+		&self,
+		intake: IntakeId<UniversityId>,
+		start: Option<AccountId32>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<AccountId32, AdmissionOffer<BlockNumber>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.accepted_applications(&block_id, intake, start, limit.unwrap_or(u32::MAX))
+			.map_err(|e| runtime_error("Unable to query accepted applications.", e))
+	}
+}
@@ -1 +1,6 @@
+pub mod certificate;
+pub mod error;
+pub mod intake;
+pub mod professor;
 pub mod university;
+pub mod validation;
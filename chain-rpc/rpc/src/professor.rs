@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sp_api::{BlockId, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{traits::Block as BlockT, AccountId32};
+use types::{primitives::Page, professor::*, university::UniversityId};
+
+pub use chain_rpc_runtime_api::professor::PalletProfessorApi as ProfessorRuntimeApi;
+
+use crate::error::runtime_error;
+
+#[rpc(client, server)]
+pub trait ProfessorApi<BlockHash, BlockNumber> {
+	/// page of professors starting after `start`
+	#[method(name = "professor_list")]
+	fn professors(
+		&self,
+		start: Option<ProfessorId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<ProfessorId, ProfessorInfo<AccountId32>>>;
+
+	/// page of open offers starting after `start`
+	#[method(name = "professor_listOffers")]
+	fn offers(
+		&self,
+		start: Option<OfferId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<OfferId, OfferInfo<BlockNumber>>>;
+
+	/// page of open offers made to `professor` starting after `start`
+	#[method(name = "professor_listProfessorOffers")]
+	fn professor_offers(
+		&self,
+		professor: ProfessorId,
+		start: Option<OfferId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<OfferId, OfferInfo<BlockNumber>>>;
+
+	/// page of universities `professor` is or was affiliated with starting after `start`
+	#[method(name = "professor_listAffiliations")]
+	fn affiliations(
+		&self,
+		professor: ProfessorId,
+		start: Option<UniversityId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<UniversityId, Affiliation<BlockNumber>>>;
+
+	/// page of professors currently in faculty of `university` starting after `start`
+	#[method(name = "professor_listFaculty")]
+	fn faculty(
+		&self,
+		university: UniversityId,
+		start: Option<ProfessorId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<ProfessorId, Affiliation<BlockNumber>>>;
+}
+
+pub struct Professor<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Professor<C, P> {
+	/// Creates a new instance of the Professor Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, BlockNumber> ProfessorApiServer<<Block as BlockT>::Hash, BlockNumber>
+	for Professor<C, Block>
+where
+	Block: BlockT,
+	BlockNumber: Codec + Send + Sync + 'static + serde::Serialize,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ProfessorRuntimeApi<Block, BlockNumber>,
+{
+	fn professors(
+		&self,
+		start: Option<ProfessorId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<ProfessorId, ProfessorInfo<AccountId32>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.professors(&block_id, start, limit.unwrap_or(u32::MAX))
+			.map_err(|e| runtime_error("Unable to query professors.", e))
+	}
+
+	fn offers(
+		&self,
+		start: Option<OfferId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<OfferId, OfferInfo<BlockNumber>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.offers(&block_id, start, limit.unwrap_or(u32::MAX))
+			.map_err(|e| runtime_error("Unable to query offers.", e))
+	}
+
+	fn professor_offers(
+		&self,
+		professor: ProfessorId,
+		start: Option<OfferId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<OfferId, OfferInfo<BlockNumber>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.professor_offers(&block_id, professor, start, limit.unwrap_or(u32::MAX))
+			.map_err(|e| runtime_error("Unable to query offers.", e))
+	}
+
+	fn affiliations(
+		&self,
+		professor: ProfessorId,
+		start: Option<UniversityId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<UniversityId, Affiliation<BlockNumber>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.affiliations(&block_id, professor, start, limit.unwrap_or(u32::MAX))
+			.map_err(|e| runtime_error("Unable to query affiliations.", e))
+	}
+
+	fn faculty(
+		&self,
+		university: UniversityId,
+		start: Option<ProfessorId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<ProfessorId, Affiliation<BlockNumber>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.faculty(&block_id, university, start, limit.unwrap_or(u32::MAX))
+			.map_err(|e| runtime_error("Unable to query faculty.", e))
+	}
+}
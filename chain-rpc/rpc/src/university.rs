@@ -3,15 +3,27 @@ use std::sync::Arc;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use sp_api::{BlockId, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::Block as BlockT;
-use types::university::*;
+use sp_runtime::{traits::Block as BlockT, AccountId32};
+use types::{primitives::Page, university::*};
 
 pub use chain_rpc_runtime_api::university::PalletUniversityApi as UniversityRuntimeApi;
 
+use crate::error::runtime_error;
+
 #[rpc(client, server)]
 pub trait UniversityApi<BlockHash> {
 	#[method(name = "university_get_all_id")]
 	fn all_university_id(&self, at: Option<BlockHash>) -> RpcResult<Vec<UniversityId>>;
+
+	/// page of universities starting after `start`
+	/// `limit` defaults to and is capped by the runtime maximum
+	#[method(name = "university_list")]
+	fn universities(
+		&self,
+		start: Option<UniversityId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<UniversityId, types::university::University<AccountId32>>>;
 }
 
 pub struct University<C, P> {
@@ -21,7 +33,7 @@ pub struct University<C, P> {
 }
 
 impl<C, P> University<C, P> {
-	/// Creates a new instance of the TransactionPayment Rpc helper.
+	/// Creates a new instance of the University Rpc helper.
 	pub fn new(client: Arc<C>) -> Self {
 		Self { client, _marker: Default::default() }
 	}
@@ -36,8 +48,20 @@ where
 	fn all_university_id(&self, at: Option<Block::Hash>) -> RpcResult<Vec<UniversityId>> {
 		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
 		let api = self.client.runtime_api();
-		let all_id = api.universities_keys(&block_id).unwrap();
+		api.universities_keys(&block_id)
+			.map_err(|e| runtime_error("Unable to query university ids.", e))
+	}
+
+	fn universities(
+		&self,
+		start: Option<UniversityId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<UniversityId, types::university::University<AccountId32>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
 
-		Ok(all_id)
+		api.universities(&block_id, start, limit.unwrap_or(u32::MAX))
+			.map_err(|e| runtime_error("Unable to query universities.", e))
 	}
 }
@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sp_api::{BlockId, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{traits::Block as BlockT, AccountId32};
+use types::{primitives::Page, professor::ProfessorId, university::UniversityId, validator::*};
+
+pub use chain_rpc_runtime_api::validation::PalletValidationApi as ValidationRuntimeApi;
+
+use crate::error::runtime_error;
+
+#[rpc(client, server)]
+pub trait ValidationApi<BlockHash, BlockNumber> {
+	/// validator set by governance to accredit other validators
+	#[method(name = "validation_rootValidator")]
+	fn root_validator(&self, at: Option<BlockHash>) -> RpcResult<Option<ValidatorId>>;
+
+	/// page of validators starting after `start`
+	#[method(name = "validation_listValidators")]
+	fn validators(
+		&self,
+		start: Option<ValidatorId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<ValidatorId, ValidatorInfo<AccountId32>>>;
+
+	/// page of universities validated by `validator` starting after `start`
+	#[method(name = "validation_listUniversities")]
+	fn validated_universities(
+		&self,
+		validator: ValidatorId,
+		start: Option<UniversityId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<UniversityId, ValidatedUniversityInfo<BlockNumber>>>;
+
+	/// page of professors validated by `validator` starting after `start`
+	#[method(name = "validation_listProfessors")]
+	fn validated_professors(
+		&self,
+		validator: ValidatorId,
+		start: Option<ProfessorId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<ProfessorId, ValidatedProfessorInfo<BlockNumber>>>;
+}
+
+pub struct Validation<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Validation<C, P> {
+	/// Creates a new instance of the Validation Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, BlockNumber> ValidationApiServer<<Block as BlockT>::Hash, BlockNumber>
+	for Validation<C, Block>
+where
+	Block: BlockT,
+	BlockNumber: Codec + Send + Sync + 'static + serde::Serialize,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ValidationRuntimeApi<Block, BlockNumber>,
+{
+	fn root_validator(&self, at: Option<Block::Hash>) -> RpcResult<Option<ValidatorId>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.root_validator(&block_id)
+			.map_err(|e| runtime_error("Unable to query root validator.", e))
+	}
+
+	fn validators(
+		&self,
+		start: Option<ValidatorId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<ValidatorId, ValidatorInfo<AccountId32>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.validators(&block_id, start, limit.unwrap_or(u32::MAX))
+			.map_err(|e| runtime_error("Unable to query validators.", e))
+	}
+
+	fn validated_universities(
+		&self,
+		validator: ValidatorId,
+		start: Option<UniversityId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<UniversityId, ValidatedUniversityInfo<BlockNumber>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.validated_universities(&block_id, validator, start, limit.unwrap_or(u32::MAX))
+			.map_err(|e| runtime_error("Unable to query validated universities.", e))
+	}
+
+	fn validated_professors(
+		&self,
+		validator: ValidatorId,
+		start: Option<ProfessorId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<ProfessorId, ValidatedProfessorInfo<BlockNumber>>> {
+		let block_id = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let api = self.client.runtime_api();
+
+		api.validated_professors(&block_id, validator, start, limit.unwrap_or(u32::MAX))
+			.map_err(|e| runtime_error("Unable to query validated professors.", e))
+	}
+}
@@ -0,0 +1,29 @@
+use codec::Codec;
+use sp_std::vec::Vec as SpStdVec;
+use types::{certificate::*, merkle::MerkleHash, validator::ValidatorId};
+
+sp_api::decl_runtime_apis! {
+	pub trait PalletCertificateApi<BlockNumber>
+	where
+		BlockNumber: Codec,
+	{
+		/// verify the certificate against the hash of presented document
+		/// if `validator` is passed, also report whether issuer is validated by it
+		fn certificate_verify(
+			certificate_id: CertificateId,
+			document_hash: DocumentHash,
+			validator: Option<ValidatorId>,
+		) -> Option<CertificateVerification<BlockNumber>>;
+
+		/// verify the certificate included in batch `batch_id` with merkle `proof`
+		fn certificate_batch_verify(
+			batch_id: CertificateBatchId,
+			leaf: CertificateLeaf,
+			proof: SpStdVec<MerkleHash>,
+			validator: Option<ValidatorId>,
+		) -> Option<CertificateVerification<BlockNumber>>;
+
+		/// timestamp in milliseconds of the block this is called at
+		fn block_timestamp() -> u64;
+	}
+}
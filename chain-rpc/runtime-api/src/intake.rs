@@ -0,0 +1,30 @@
+use codec::Codec;
+use sp_runtime::AccountId32;
+use types::{intake::*, primitives::Page, university::UniversityId};
+
+sp_api::decl_runtime_apis! {
+	pub trait PalletIntakeApi<BlockNumber>
+	where
+		BlockNumber: Codec,
+	{
+		/// page of intakes announced by `university` starting after intake index `start`
+		/// `limit` is capped by the runtime
+		fn intakes(
+			university: UniversityId,
+			start: Option<u32>,
+			limit: u32,
+		) -> Page<IntakeId<UniversityId>, IntakeInfo<BlockNumber>>;
+		/// page of applications made to `intake` starting after applicant `start`
+		fn applications(
+			intake: IntakeId<UniversityId>,
+			start: Option<AccountId32>,
+			limit: u32,
+		) -> Page<AccountId32, IntakeApplication<BlockNumber>>;
+		/// page of students accepted in `intake` starting after student `start`
+		fn accepted_applications(
+			intake: IntakeId<UniversityId>,
+			start: Option<AccountId32>,
+			limit: u32,
+		) -> Page<AccountId32, AdmissionOffer<BlockNumber>>;
+	}
+}
@@ -1,3 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod certificate;
+pub mod intake;
+pub mod professor;
 pub mod university;
+pub mod validation;
@@ -0,0 +1,34 @@
+use codec::Codec;
+use sp_runtime::AccountId32;
+use types::{primitives::Page, professor::*, university::UniversityId};
+
+sp_api::decl_runtime_apis! {
+	pub trait PalletProfessorApi<BlockNumber>
+	where
+		BlockNumber: Codec,
+	{
+		/// page of professors starting after `start`
+		/// `limit` is capped by the runtime
+		fn professors(start: Option<ProfessorId>, limit: u32) -> Page<ProfessorId, ProfessorInfo<AccountId32>>;
+		/// page of open offers starting after `start`
+		fn offers(start: Option<OfferId>, limit: u32) -> Page<OfferId, OfferInfo<BlockNumber>>;
+		/// page of open offers made to `professor` starting after `start`
+		fn professor_offers(
+			professor: ProfessorId,
+			start: Option<OfferId>,
+			limit: u32,
+		) -> Page<OfferId, OfferInfo<BlockNumber>>;
+		/// page of universities `professor` is or was affiliated with starting after `start`
+		fn affiliations(
+			professor: ProfessorId,
+			start: Option<UniversityId>,
+			limit: u32,
+		) -> Page<UniversityId, Affiliation<BlockNumber>>;
+		/// page of professors currently in faculty of `university` starting after `start`
+		fn faculty(
+			university: UniversityId,
+			start: Option<ProfessorId>,
+			limit: u32,
+		) -> Page<ProfessorId, Affiliation<BlockNumber>>;
+	}
+}
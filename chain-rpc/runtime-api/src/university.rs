@@ -1,13 +1,14 @@
-#![cfg_attr(not(feature = "std"), no_std)]
-
 use sp_runtime::AccountId32;
 use sp_std::vec::Vec as SpStdVec;
-use types::university::*;
+use types::{primitives::Page, university::*};
 
 sp_api::decl_runtime_apis! {
 	pub trait PalletUniversityApi
 	{
 		fn universities_keys() -> SpStdVec<UniversityId>;
 		fn university_by_id(id: UniversityId) -> Option<University<AccountId32>>;
+		/// page of universities starting after `start`
+		/// `limit` is capped by the runtime
+		fn universities(start: Option<UniversityId>, limit: u32) -> Page<UniversityId, University<AccountId32>>;
 	}
 }
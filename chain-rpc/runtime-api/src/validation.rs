@@ -0,0 +1,28 @@
+use codec::Codec;
+use sp_runtime::AccountId32;
+use types::{primitives::Page, professor::ProfessorId, university::UniversityId, validator::*};
+
+sp_api::decl_runtime_apis! {
+	pub trait PalletValidationApi<BlockNumber>
+	where
+		BlockNumber: Codec,
+	{
+		/// validator set by governance to accredit other validators
+		fn root_validator() -> Option<ValidatorId>;
+		/// page of validators starting after `start`
+		/// `limit` is capped by the runtime
+		fn validators(start: Option<ValidatorId>, limit: u32) -> Page<ValidatorId, ValidatorInfo<AccountId32>>;
+		/// page of universities validated by `validator` starting after `start`
+		fn validated_universities(
+			validator: ValidatorId,
+			start: Option<UniversityId>,
+			limit: u32,
+		) -> Page<UniversityId, ValidatedUniversityInfo<BlockNumber>>;
+		/// page of professors validated by `validator` starting after `start`
+		fn validated_professors(
+			validator: ValidatorId,
+			start: Option<ProfessorId>,
+			limit: u32,
+		) -> Page<ProfessorId, ValidatedProfessorInfo<BlockNumber>>;
+	}
+}
@@ -0,0 +1,120 @@
+//! Build the merkle tree of a certificate batch from a CSV of graduates
+//!
+//! every non-empty line of the CSV is `student_id,document_hash[,programme_id]`
+//! with hex encoded values. an optional header line starting with `student` is skipped.
+//! the output is the JSON holding the root, the leaves to pass to `issue_certificate_batch`
+//! and the merkle proof of every certificate to hand over to it's holder.
+
+use std::{fs, path::PathBuf};
+
+use serde::Serialize;
+use sp_core::{bytes::from_hex, H256};
+use types::{
+    certificate::{CertificateLeaf, DocumentHash},
+    merkle::{merkle_proof, merkle_root},
+    student::StudentId,
+    university::ProgrammeId,
+};
+
+/// Build the merkle root and proofs of a certificate batch from a CSV of graduates
+#[derive(Debug, clap::Parser)]
+pub struct CertificateBatchCmd {
+    /// CSV file with `student_id,document_hash[,programme_id]` on every line
+    #[arg(long)]
+    pub input: PathBuf,
+
+    /// Write the output to this file instead of stdout
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Merkle tree of a certificate batch
+#[derive(Debug, Serialize)]
+pub struct CertificateBatch {
+    pub root: H256,
+    pub count: u32,
+    pub certificates: Vec<BatchedCertificate>,
+}
+
+/// Certificate with it's inclusion proof in the batch
+#[derive(Debug, Serialize)]
+pub struct BatchedCertificate {
+    pub leaf: CertificateLeaf,
+    pub proof: Vec<H256>,
+}
+
+impl CertificateBatchCmd {
+    pub fn run(&self) -> sc_cli::Result<()> {
+        let csv = fs::read_to_string(&self.input)?;
+        let batch = build_batch(&csv).map_err(sc_cli::Error::Input)?;
+        let json = serde_json::to_string_pretty(&batch)
+            .map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+
+        match &self.output {
+            Some(path) => fs::write(path, json)?,
+            None => println!("{}", json),
+        }
+        Ok(())
+    }
+}
+
+/// build the merkle tree of every certificate listed in `csv`
+pub fn build_batch(csv: &str) -> Result<CertificateBatch, String> {
+    let leaves = csv
+        .lines()
+        .enumerate()
+        .map(|(at, line)| (at + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with("student"))
+        .map(|(line_number, line)| {
+            parse_leaf(line).map_err(|e| format!("line {}: {}", line_number, e))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let hashes = leaves.iter().map(CertificateLeaf::hash).collect::<Vec<_>>();
+    let root = merkle_root(&hashes).ok_or("no certificate in the batch")?;
+    let count = u32::try_from(leaves.len()).map_err(|_| "too many certificates in the batch")?;
+    let certificates = leaves
+        .into_iter()
+        .enumerate()
+        .map(|(index, leaf)| BatchedCertificate {
+            leaf,
+            proof: merkle_proof(&hashes, index)
+                .expect("index is within leaves")
+                .into_iter()
+                .map(H256::from)
+                .collect(),
+        })
+        .collect();
+
+    Ok(CertificateBatch {
+        root: root.into(),
+        count,
+        certificates,
+    })
+}
+
+fn parse_leaf(line: &str) -> Result<CertificateLeaf, String> {
+    let mut fields = line.split(',').map(str::trim);
+    let student: StudentId = fields
+        .next()
+        .ok_or("missing student id")?
+        .parse()
+        .map_err(|e| format!("invalid student id: {}", e))?;
+    let document_hash: DocumentHash = fields
+        .next()
+        .and_then(|hash| from_hex(hash).ok())
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or("invalid document hash")?;
+    let programme = fields
+        .next()
+        .filter(|programme| !programme.is_empty())
+        .map(|programme| programme.parse::<ProgrammeId>())
+        .transpose()
+        .map_err(|e| format!("invalid programme id: {}", e))?;
+
+    Ok(CertificateLeaf {
+        student,
+        programme,
+        document_hash,
+    })
+}
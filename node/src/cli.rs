@@ -50,4 +50,7 @@ pub enum Subcommand {
 
     /// Db meta columns information.
     ChainInfo(sc_cli::ChainInfoCmd),
+
+    /// Build the merkle root and proofs of a certificate batch from a CSV of graduates.
+    CertificateBatch(crate::certificate_batch::CertificateBatchCmd),
 }
@@ -5,6 +5,7 @@ mod chain_spec;
 #[macro_use]
 mod service;
 mod benchmarking;
+mod certificate_batch;
 mod cli;
 mod command;
 mod rpc;
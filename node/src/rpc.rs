@@ -34,7 +34,7 @@ use educhain_development_runtime as educhain_runtime;
 
 use std::sync::Arc;
 
-use educhain_runtime::{opaque::Block, AccountId, Balance, Index};
+use educhain_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Index};
 use jsonrpsee::RpcModule;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
@@ -64,8 +64,20 @@ where
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: BlockBuilder<Block>,
+    C::Api: chain_rpc::university::UniversityRuntimeApi<Block>,
+    C::Api: chain_rpc::certificate::CertificateRuntimeApi<Block, BlockNumber>,
+    C::Api: chain_rpc::professor::ProfessorRuntimeApi<Block, BlockNumber>,
+    C::Api: chain_rpc::intake::IntakeRuntimeApi<Block, BlockNumber>,
+    C::Api: chain_rpc::validation::ValidationRuntimeApi<Block, BlockNumber>,
     P: TransactionPool + 'static,
 {
+    use chain_rpc::{
+        certificate::{Certificate, CertificateApiServer},
+        intake::{Intake, IntakeApiServer},
+        professor::{Professor, ProfessorApiServer},
+        university::{University, UniversityApiServer},
+        validation::{Validation, ValidationApiServer},
+    };
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -78,6 +90,11 @@ where
 
     module.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(University::new(client.clone()).into_rpc())?;
+    module.merge(Certificate::new(client.clone()).into_rpc())?;
+    module.merge(Professor::new(client.clone()).into_rpc())?;
+    module.merge(Intake::new(client.clone()).into_rpc())?;
+    module.merge(Validation::new(client.clone()).into_rpc())?;
 
     // Extend this RPC with a custom API by using the following syntax.
     // `YourRpcStruct` should have a reference to a client, which is needed
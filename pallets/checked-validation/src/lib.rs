@@ -2,16 +2,30 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::{pallet_prelude::*, Twox64Concat};
+    use frame_support::{
+        pallet_prelude::*,
+        sp_runtime::traits::{One, Saturating, Zero},
+        sp_std::vec::Vec,
+        traits::{Currency, ReservableCurrency},
+        Twox64Concat,
+    };
     use frame_system::pallet_prelude::{OriginFor, *};
     use pallet_provider_traits::UniversityProvider;
     pub(super) use traits::pallet_provider as pallet_provider_traits;
-    use traits::pallet_provider::ProfessorProvider;
-    use types::validator::{
-        ValidatedProfessorInfo, ValidatedUniversityInfo, ValidatorId, ValidatorInfoFor, *,
+    use traits::{
+        id_generator::{bump_nonce, IdGenerator},
+        pallet_provider::ProfessorProvider,
     };
+    use types::primitives::{AccountIdOf, IdEntityKind};
+    use types::validator::{ValidatorId, ValidatorInfoFor, *};
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
@@ -27,13 +41,36 @@ pub mod pallet {
         >;
         type StudentProvider: pallet_provider_traits::StudentProvider;
         type ProfessorProvider: ProfessorProvider<ProfessorId = types::professor::ProfessorId>;
+
+        /// Currency in which validator deposit is reserved
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Deposit reserved from admin of a self registered validator
+        #[pallet::constant]
+        type ValidatorDeposit: Get<BalanceOf<Self>>;
+
+        /// Maximum number of blocks a validation can be granted or renewed for
+        #[pallet::constant]
+        type MaxValidityPeriod: Get<Self::BlockNumber>;
+
+        /// Maximum number of validations that can be expired in a single block
+        /// remaining validations are carried over to next block
+        #[pallet::constant]
+        type MaxValidationExpiriesPerBlock: Get<u32>;
     }
 
-    type UniversityIdOf<T> =
+    pub(super) type UniversityIdOf<T> =
         <<T as Config>::UniversityProvider as UniversityProvider>::UniversityId;
-    type ProfessorIdOf<T> = <<T as Config>::ProfessorProvider as ProfessorProvider>::ProfessorId;
-    type NewValidatedUniversityInfoFor<T> = ValidateUniversityParam<UniversityIdOf<T>>;
-    type NewValidatedProfessorInfoFor<T> = ValidateProfessorParam<ProfessorIdOf<T>>;
+    pub(super) type ProfessorIdOf<T> =
+        <<T as Config>::ProfessorProvider as ProfessorProvider>::ProfessorId;
+    pub(super) type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+    pub(super) type ValidationRecordFor<T> = ValidationRecord<BlockNumberFor<T>>;
+    pub(super) type ValidationTargetOf<T> = ValidationTarget<UniversityIdOf<T>, ProfessorIdOf<T>>;
+    type ValidationTermsFor<T> = ValidationTerms<BlockNumberFor<T>>;
+    type NewValidatedUniversityInfoFor<T> =
+        ValidateUniversityParam<UniversityIdOf<T>, BlockNumberFor<T>>;
+    type NewValidatedProfessorInfoFor<T> =
+        ValidateProfessorParam<ProfessorIdOf<T>, BlockNumberFor<T>>;
 
     /// Storage that keeps track of validators
     /// key in ValidatorId hashed by Twox64Concat and value is ValidatorInfo
@@ -41,6 +78,25 @@ pub mod pallet {
     #[pallet::getter(fn get_validator)]
     pub type Validators<T> = StorageMap<_, Twox64Concat, ValidatorId, ValidatorInfoFor<T>>;
 
+    /// Validator set by governance to approve accredited validators
+    #[pallet::storage]
+    #[pallet::getter(fn root_validator)]
+    pub type RootValidator<T> = StorageValue<_, ValidatorId>;
+
+    /// Account and amount reserved for the self registered validator
+    #[pallet::storage]
+    pub type ValidatorDeposits<T> =
+        StorageMap<_, Twox64Concat, ValidatorId, (AccountIdOf<T>, BalanceOf<T>)>;
+
+    /// Ids of deregistered validators, never to be registered again
+    /// so that validations they granted cannot be inherited
+    #[pallet::storage]
+    pub type RetiredValidators<T> = StorageMap<_, Twox64Concat, ValidatorId, ()>;
+
+    /// Nonce used to derive chain assigned `ValidatorId`
+    #[pallet::storage]
+    pub type IdNonce<T> = StorageValue<_, u64, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn get_university)]
     pub type Universities<T> = StorageDoubleMap<
@@ -49,7 +105,7 @@ pub mod pallet {
         ValidatorId,
         Twox64Concat,
         UniversityIdOf<T>,
-        ValidatedUniversityInfo,
+        ValidationRecordFor<T>,
     >;
 
     #[pallet::storage]
@@ -60,9 +116,35 @@ pub mod pallet {
         ValidatorId,
         Twox64Concat,
         ProfessorIdOf<T>,
-        ValidatedProfessorInfo,
+        ValidationRecordFor<T>,
     >;
 
+    /// Validators that have validated the university
+    #[pallet::storage]
+    pub type UniversityValidators<T> =
+        StorageDoubleMap<_, Twox64Concat, UniversityIdOf<T>, Twox64Concat, ValidatorId, ()>;
+
+    /// Validators that have validated the professor
+    #[pallet::storage]
+    pub type ProfessorValidators<T> =
+        StorageDoubleMap<_, Twox64Concat, ProfessorIdOf<T>, Twox64Concat, ValidatorId, ()>;
+
+    /// Validations keyed by the block they expire on
+    #[pallet::storage]
+    pub type ValidationExpiryLookup<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Twox64Concat,
+        (ValidatorId, ValidationTargetOf<T>),
+        (),
+    >;
+
+    /// First block whose expiring validations are yet to be processed
+    /// only set when expiries have been carried over
+    #[pallet::storage]
+    pub type ExpiryCursor<T> = StorageValue<_, BlockNumberFor<T>>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -70,15 +152,42 @@ pub mod pallet {
         ValidatorAdded(ValidatorId),
         /// Validator have been removed
         ValidatorRemoved(ValidatorId),
+        /// Validator have been made the root validator by governance
+        RootValidatorSet(ValidatorId),
+        /// Validator have been accredited by the root validator
+        ValidatorAccredited(ValidatorId),
+        /// Accreditation of validator have been revoked by the root validator
+        AccreditationRevoked(ValidatorId),
         /// University have been validated
         UniversityValidated {
             validated_by: ValidatorId,
             university: UniversityIdOf<T>,
+            level: AccreditationLevel,
+            valid_until: BlockNumberFor<T>,
         },
         /// Professor have been validated
         ProfessorValidated {
             validated_by: ValidatorId,
             professor: ProfessorIdOf<T>,
+            level: AccreditationLevel,
+            valid_until: BlockNumberFor<T>,
+        },
+        /// Validation have been withdrawn by validator
+        ValidationRevoked {
+            validated_by: ValidatorId,
+            target: ValidationTargetOf<T>,
+        },
+        /// Validation have been renewed with new terms
+        ValidationRenewed {
+            validated_by: ValidatorId,
+            target: ValidationTargetOf<T>,
+            level: AccreditationLevel,
+            valid_until: BlockNumberFor<T>,
+        },
+        /// Validation was not renewed before it's expiry
+        ValidationExpired {
+            validated_by: ValidatorId,
+            target: ValidationTargetOf<T>,
         },
     }
 
@@ -94,6 +203,24 @@ pub mod pallet {
         UniversityAlreadyValidated,
         /// ProfessorAlreadyValidated
         ProfessorAlreadyValidated,
+        /// No university with given id exists
+        NoUniversity,
+        /// No professor with given id exists
+        NoProfessor,
+        /// Entity have not been validated by this validator
+        NotValidated,
+        /// Validity is zero or longer than `MaxValidityPeriod`
+        InvalidValidity,
+        /// Root validator have not been set yet
+        NoRootValidator,
+        /// Only open validators can be accredited
+        AlreadyAccredited,
+        /// Validator is not accredited
+        NotAccredited,
+        /// Root validator cannot be removed until it is replaced
+        IsRootValidator,
+        /// Validator with given Id have been deregistered and cannot be reused
+        ValidatorRetired,
     }
 
     #[pallet::call]
@@ -101,55 +228,162 @@ pub mod pallet {
         /// Register a validator
         /// Can be called by any signed user
         /// need to pass ValidatorId as paramater and insert that into Validators storage
+        /// if ValidatorId is not passed, chain will assign one
+        /// validator starts in open tier and `ValidatorDeposit` is reserved from caller
         /// weight is 10_000
         #[pallet::weight(10_000)]
         pub fn register_validator(
             origin: OriginFor<T>,
-            validator_id: ValidatorId,
+            validator_id: Option<ValidatorId>,
             validator_info: NewValidatorParam,
         ) -> DispatchResult {
             let admin = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let validator_id = validator_id
+                .unwrap_or_else(|| <Self as IdGenerator<AccountIdOf<T>>>::generate_id(&admin));
             Self::verify_new_id(&validator_id)?;
 
+            let deposit = T::ValidatorDeposit::get();
+            T::Currency::reserve(&admin, deposit)?;
+
             let NewValidatorParam { document } = validator_info;
-            let validator_info = ValidatorInfo { document, admin };
+            let validator_info = ValidatorInfo {
+                document,
+                admin: admin.clone(),
+                tier: ValidatorTier::Open,
+            };
 
             Validators::<T>::insert(&validator_id, validator_info);
+            ValidatorDeposits::<T>::insert(&validator_id, (admin, deposit));
 
             Self::deposit_event(Event::ValidatorAdded(validator_id));
 
             Ok(())
         }
 
+        /// remove the validator and return it's deposit
+        /// validations granted by it are no longer reported and expire on their own
+        /// the id is retired so that it cannot be registered again
+        #[pallet::weight(10_000)]
+        pub fn deregister_validator(
+            origin: OriginFor<T>,
+            validator_id: ValidatorId,
+        ) -> DispatchResult {
+            Self::ensure_validator_admin(origin, &validator_id)?;
+            ensure!(
+                Self::root_validator().as_ref() != Some(&validator_id),
+                Error::<T>::IsRootValidator
+            );
+
+            Validators::<T>::remove(&validator_id);
+            RetiredValidators::<T>::insert(&validator_id, ());
+            if let Some((depositor, deposit)) = ValidatorDeposits::<T>::take(&validator_id) {
+                T::Currency::unreserve(&depositor, deposit);
+            }
+            Self::deposit_event(Event::ValidatorRemoved(validator_id));
+
+            Ok(())
+        }
+
+        /// make an existing validator the root validator
+        /// previous root validator, if any, stays accredited
+        #[pallet::weight(10_000)]
+        pub fn set_root_validator(
+            origin: OriginFor<T>,
+            validator_id: ValidatorId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::set_tier(&validator_id, ValidatorTier::Root)?;
+
+            if let Some(previous) = RootValidator::<T>::get() {
+                if previous != validator_id {
+                    Self::set_tier(&previous, ValidatorTier::Accredited)?;
+                }
+            }
+            RootValidator::<T>::put(&validator_id);
+            Self::deposit_event(Event::RootValidatorSet(validator_id));
+
+            Ok(())
+        }
+
+        /// accredit an open validator
+        /// can only be called by admin of the root validator
+        #[pallet::weight(10_000)]
+        pub fn accredit_validator(
+            origin: OriginFor<T>,
+            validator_id: ValidatorId,
+        ) -> DispatchResult {
+            Self::ensure_root_validator_admin(origin)?;
+            let validator =
+                Self::get_validator(&validator_id).ok_or(Error::<T>::ValidatorDoesNotExist)?;
+            ensure!(
+                validator.tier == ValidatorTier::Open,
+                Error::<T>::AlreadyAccredited
+            );
+
+            Self::set_tier(&validator_id, ValidatorTier::Accredited)?;
+            Self::deposit_event(Event::ValidatorAccredited(validator_id));
+
+            Ok(())
+        }
+
+        /// move an accredited validator back to open tier
+        /// can only be called by admin of the root validator
+        #[pallet::weight(10_000)]
+        pub fn revoke_accreditation(
+            origin: OriginFor<T>,
+            validator_id: ValidatorId,
+        ) -> DispatchResult {
+            Self::ensure_root_validator_admin(origin)?;
+            let validator =
+                Self::get_validator(&validator_id).ok_or(Error::<T>::ValidatorDoesNotExist)?;
+            ensure!(
+                validator.tier == ValidatorTier::Accredited,
+                Error::<T>::NotAccredited
+            );
+
+            Self::set_tier(&validator_id, ValidatorTier::Open)?;
+            Self::deposit_event(Event::AccreditationRevoked(validator_id));
+
+            Ok(())
+        }
+
         #[pallet::weight(10_000)]
         pub fn validate_university(
             origin: OriginFor<T>,
             validator_id: ValidatorId,
             validated_info: NewValidatedUniversityInfoFor<T>,
         ) -> DispatchResult {
-            // provide a mechanism to have a list of validator
-            // and the edu-chain validatior will be assigned validator_id = 0
-            // this provides a mechanism to validate the kyc of university
-            // and this validation can be other than edu-chain itself
-            // it's upto client to trust which validator
+            // validators of any tier can validate the kyc of university
+            // it's upto client to trust which validator,
+            // `ValidatorTier` tells how much the chain itself trusts it
 
             Self::ensure_validator_admin(origin, &validator_id)?;
             ensure!(
-                Universities::<T>::contains_key(&validator_id, &validated_info.university),
+                T::UniversityProvider::university_info(&validated_info.university).is_some(),
+                Error::<T>::NoUniversity
+            );
+            ensure!(
+                !Universities::<T>::contains_key(&validator_id, &validated_info.university),
                 Error::<T>::UniversityAlreadyValidated
             );
 
             let NewValidatedUniversityInfoFor::<T> {
                 university: university_id,
+                terms,
             } = validated_info;
+            let record = Self::new_record(terms)?;
+            let (level, valid_until) = (record.level, record.valid_until);
 
-            let validated_info = ValidatedUniversityInfo {};
-
-            Universities::<T>::insert(&validator_id, &university_id, validated_info);
-
+            Self::insert_record(
+                &validator_id,
+                ValidationTarget::University(university_id.clone()),
+                record,
+            );
             Self::deposit_event(Event::<T>::UniversityValidated {
                 validated_by: validator_id,
                 university: university_id,
+                level,
+                valid_until,
             });
 
             Ok(())
@@ -163,26 +397,93 @@ pub mod pallet {
         ) -> DispatchResult {
             Self::ensure_validator_admin(origin, &validator_id)?;
             ensure!(
-                Professors::<T>::contains_key(&validator_id, &validated_info.professor),
+                T::ProfessorProvider::professor_info(&validated_info.professor).is_some(),
+                Error::<T>::NoProfessor
+            );
+            ensure!(
+                !Professors::<T>::contains_key(&validator_id, &validated_info.professor),
                 Error::<T>::ProfessorAlreadyValidated
             );
 
             let NewValidatedProfessorInfoFor::<T> {
                 professor: professor_id,
+                terms,
             } = validated_info;
+            let record = Self::new_record(terms)?;
+            let (level, valid_until) = (record.level, record.valid_until);
 
-            let validated_info = ValidatedProfessorInfo {};
-
-            Professors::<T>::insert(&validator_id, &professor_id, validated_info);
+            Self::insert_record(
+                &validator_id,
+                ValidationTarget::Professor(professor_id.clone()),
+                record,
+            );
             Self::deposit_event(Event::<T>::ProfessorValidated {
                 validated_by: validator_id,
                 professor: professor_id,
+                level,
+                valid_until,
+            });
+
+            Ok(())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn revoke_validation(
+            origin: OriginFor<T>,
+            validator_id: ValidatorId,
+            target: ValidationTargetOf<T>,
+        ) -> DispatchResult {
+            Self::ensure_validator_admin(origin, &validator_id)?;
+            Self::take_record(&validator_id, &target).ok_or(Error::<T>::NotValidated)?;
+
+            Self::deposit_event(Event::<T>::ValidationRevoked {
+                validated_by: validator_id,
+                target,
+            });
+
+            Ok(())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn renew_validation(
+            origin: OriginFor<T>,
+            validator_id: ValidatorId,
+            target: ValidationTargetOf<T>,
+            terms: ValidationTermsFor<T>,
+        ) -> DispatchResult {
+            // validation window restarts from current block with the new terms
+            Self::ensure_validator_admin(origin, &validator_id)?;
+            let record = Self::new_record(terms)?;
+            Self::take_record(&validator_id, &target).ok_or(Error::<T>::NotValidated)?;
+            let (level, valid_until) = (record.level, record.valid_until);
+
+            Self::insert_record(&validator_id, target.clone(), record);
+            Self::deposit_event(Event::<T>::ValidationRenewed {
+                validated_by: validator_id,
+                target,
+                level,
+                valid_until,
             });
 
             Ok(())
         }
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(current_block_number: BlockNumberFor<T>) -> Weight {
+            Self::expire_validations(current_block_number)
+        }
+    }
+
+    impl<T: Config> IdGenerator<AccountIdOf<T>> for Pallet<T> {
+        const ENTITY: IdEntityKind = IdEntityKind::Validator;
+
+        fn next_nonce() -> u64 {
+            IdNonce::<T>::mutate(bump_nonce)
+        }
+    }
+
     impl<T: Config> Pallet<T> {
         pub fn ensure_validator_admin(
             origin: OriginFor<T>,
@@ -199,11 +500,158 @@ pub mod pallet {
             Ok(())
         }
 
+        /// ensure origin is admin of the root validator
+        fn ensure_root_validator_admin(origin: OriginFor<T>) -> DispatchResult {
+            let root_validator = RootValidator::<T>::get().ok_or(Error::<T>::NoRootValidator)?;
+            Self::ensure_validator_admin(origin, &root_validator)
+        }
+
+        fn set_tier(validator_id: &ValidatorId, tier: ValidatorTier) -> DispatchResult {
+            Validators::<T>::try_mutate(validator_id, |validator| {
+                validator
+                    .as_mut()
+                    .ok_or(Error::<T>::ValidatorDoesNotExist)?
+                    .tier = tier;
+                Ok(())
+            })
+        }
+
+        /// validation record granted from current block on given terms
+        fn new_record(terms: ValidationTermsFor<T>) -> Result<ValidationRecordFor<T>, Error<T>> {
+            let ValidationTerms {
+                evidence,
+                level,
+                validity,
+                scope,
+            } = terms;
+            ensure!(
+                !validity.is_zero() && validity <= T::MaxValidityPeriod::get(),
+                Error::<T>::InvalidValidity
+            );
+
+            let valid_from = <frame_system::Pallet<T>>::block_number();
+            Ok(ValidationRecordFor::<T> {
+                evidence,
+                level,
+                valid_from,
+                valid_until: valid_from.saturating_add(validity),
+                scope,
+            })
+        }
+
+        /// store the record and schedule it's expiry
+        fn insert_record(
+            validator_id: &ValidatorId,
+            target: ValidationTargetOf<T>,
+            record: ValidationRecordFor<T>,
+        ) {
+            let valid_until = record.valid_until;
+            match &target {
+                ValidationTarget::University(university_id) => {
+                    Universities::<T>::insert(validator_id, university_id, record);
+                    UniversityValidators::<T>::insert(university_id, validator_id, ());
+                }
+                ValidationTarget::Professor(professor_id) => {
+                    Professors::<T>::insert(validator_id, professor_id, record);
+                    ProfessorValidators::<T>::insert(professor_id, validator_id, ());
+                }
+            }
+            ValidationExpiryLookup::<T>::insert(valid_until, (validator_id.clone(), target), ());
+        }
+
+        /// remove the record along with it's scheduled expiry
+        fn take_record(
+            validator_id: &ValidatorId,
+            target: &ValidationTargetOf<T>,
+        ) -> Option<ValidationRecordFor<T>> {
+            let record = match target {
+                ValidationTarget::University(university_id) => {
+                    UniversityValidators::<T>::remove(university_id, validator_id);
+                    Universities::<T>::take(validator_id, university_id)
+                }
+                ValidationTarget::Professor(professor_id) => {
+                    ProfessorValidators::<T>::remove(professor_id, validator_id);
+                    Professors::<T>::take(validator_id, professor_id)
+                }
+            }?;
+            ValidationExpiryLookup::<T>::remove(
+                record.valid_until,
+                (validator_id.clone(), target.clone()),
+            );
+
+            Some(record)
+        }
+
+        /// expire all validations that were not renewed upto `current_block_number`
+        ///
+        /// at most `MaxValidationExpiriesPerBlock` validations are expired and at most
+        /// same number of blocks are scanned. Whatever is left is carried over
+        /// to next block through `ExpiryCursor`
+        fn expire_validations(current_block_number: BlockNumberFor<T>) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let max_expiries = T::MaxValidationExpiriesPerBlock::get();
+            let mut budget = max_expiries;
+            let mut scanned = 0_u32;
+
+            let cursor = ExpiryCursor::<T>::get();
+            let mut weight_consumed = db_weight.reads(1);
+
+            let mut block_number = cursor.unwrap_or(current_block_number);
+            while block_number <= current_block_number && budget > 0 && scanned < max_expiries {
+                let mut expiring = ValidationExpiryLookup::<T>::iter_key_prefix(block_number)
+                    .take(budget as usize + 1)
+                    .collect::<Vec<_>>();
+                let drained = expiring.len() <= budget as usize;
+                expiring.truncate(budget as usize);
+                budget -= expiring.len() as u32;
+
+                // per validation: 1 read of record
+                // 3 writes to record, reverse index & lookup
+                let count = expiring.len() as u64;
+                weight_consumed += db_weight.reads_writes(1 + count, 3 * count);
+
+                for (validator_id, target) in expiring {
+                    if Self::take_record(&validator_id, &target).is_some() {
+                        Self::deposit_event(Event::<T>::ValidationExpired {
+                            validated_by: validator_id,
+                            target,
+                        });
+                    } else {
+                        ValidationExpiryLookup::<T>::remove(block_number, (validator_id, target));
+                    }
+                }
+
+                if !drained {
+                    break;
+                }
+
+                block_number = block_number.saturating_add(One::one());
+                scanned += 1;
+            }
+
+            if block_number > current_block_number {
+                // nothing is left to carry over
+                if cursor.is_some() {
+                    ExpiryCursor::<T>::kill();
+                    weight_consumed += db_weight.writes(1);
+                }
+            } else if cursor != Some(block_number) {
+                ExpiryCursor::<T>::put(block_number);
+                weight_consumed += db_weight.writes(1);
+            }
+
+            weight_consumed
+        }
+
         pub fn verify_new_id(validator_id: &ValidatorId) -> DispatchResult {
             ensure!(
-                Validators::<T>::contains_key(validator_id),
+                !Validators::<T>::contains_key(validator_id),
                 Error::<T>::ValidatorAlreadyExists,
             );
+            ensure!(
+                !RetiredValidators::<T>::contains_key(validator_id),
+                Error::<T>::ValidatorRetired,
+            );
 
             Ok(())
         }
@@ -244,8 +692,50 @@ pub mod pallet {
     // }
 }
 
+use frame_support::sp_std::vec::Vec;
 impl<T: crate::Config> crate::pallet_provider_traits::ValidationProvider for Pallet<T> {
-    fn is_verified_university() -> bool {
-        todo!()
+    type ValidatorId = types::validator::ValidatorId;
+    type UniversityId = crate::UniversityIdOf<T>;
+    type ProfessorId = crate::ProfessorIdOf<T>;
+    type ValidatedUniversityInfo = crate::ValidationRecordFor<T>;
+    type ValidatedProfessorInfo = crate::ValidationRecordFor<T>;
+
+    // records past their expiry which are yet to be pruned
+    // and records of deregistered validators are not reported
+
+    fn validated_university_info(
+        validator_id: &Self::ValidatorId,
+        university_id: &Self::UniversityId,
+    ) -> Option<Self::ValidatedUniversityInfo> {
+        let now = frame_system::Pallet::<T>::block_number();
+        crate::Pallet::<T>::get_university(validator_id, university_id)
+            .filter(|record| record.is_valid_at(&now))
+            .filter(|_| crate::Validators::<T>::contains_key(validator_id))
+    }
+
+    fn validated_professor_info(
+        validator_id: &Self::ValidatorId,
+        professor_id: &Self::ProfessorId,
+    ) -> Option<Self::ValidatedProfessorInfo> {
+        let now = frame_system::Pallet::<T>::block_number();
+        crate::Pallet::<T>::get_professor(validator_id, professor_id)
+            .filter(|record| record.is_valid_at(&now))
+            .filter(|_| crate::Validators::<T>::contains_key(validator_id))
+    }
+
+    fn validator_tier(validator_id: &Self::ValidatorId) -> Option<types::validator::ValidatorTier> {
+        crate::Pallet::<T>::get_validator(validator_id).map(|validator| validator.tier)
+    }
+
+    fn university_validators(university_id: &Self::UniversityId) -> Vec<Self::ValidatorId> {
+        crate::UniversityValidators::<T>::iter_key_prefix(university_id)
+            .filter(|validator_id| Self::is_verified_university(validator_id, university_id))
+            .collect()
+    }
+
+    fn professor_validators(professor_id: &Self::ProfessorId) -> Vec<Self::ValidatorId> {
+        crate::ProfessorValidators::<T>::iter_key_prefix(professor_id)
+            .filter(|validator_id| Self::is_verified_professor(validator_id, professor_id))
+            .collect()
     }
 }
@@ -0,0 +1,218 @@
+use crate as pallet_checked_validation;
+use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
+    traits::{ConstU16, ConstU32, ConstU64},
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+use traits::pallet_provider::{ProfessorProvider, StudentProvider, UniversityProvider};
+use types::{
+    primitives::{StdIpfsLink, UniqId},
+    professor::{NewOfferParam, OfferId, ProfessorId, ProfessorInfo},
+    university::{University, UniversityId},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+pub const VALIDATOR_ADMIN: AccountId = 1;
+pub const OTHER_ACCOUNT: AccountId = 2;
+pub const UNIVERSITY_ADMIN: AccountId = 3;
+pub const PROFESSOR_ACCOUNT: AccountId = 4;
+
+/// only university known to `MockUniversityProvider`
+pub const UNIVERSITY: UniqId = UniqId::new([1; 32]);
+/// only professor known to `MockProfessorProvider`
+pub const PROFESSOR: UniqId = UniqId::new([2; 32]);
+/// id not known to any provider
+pub const UNKNOWN: UniqId = UniqId::new([3; 32]);
+pub const VALIDATOR: UniqId = UniqId::new([4; 32]);
+
+pub const MAX_VALIDITY: u64 = 100;
+pub const VALIDATOR_DEPOSIT: u64 = 100;
+/// balance of every account at genesis
+pub const INITIAL_BALANCE: u64 = 1_000;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        CheckedValidation: pallet_checked_validation,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+impl pallet_checked_validation::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type UniversityProvider = MockUniversityProvider;
+    type StudentProvider = MockStudentProvider;
+    type ProfessorProvider = MockProfessorProvider;
+    type Currency = Balances;
+    type ValidatorDeposit = ConstU64<VALIDATOR_DEPOSIT>;
+    type MaxValidityPeriod = ConstU64<MAX_VALIDITY>;
+    type MaxValidationExpiriesPerBlock = ConstU32<2>;
+}
+
+pub fn ipfs_link() -> StdIpfsLink {
+    StdIpfsLink::new(Default::default())
+}
+
+pub struct MockUniversityProvider;
+impl UniversityProvider for MockUniversityProvider {
+    type UniversityId = UniversityId;
+    type UniversityInfo = University<AccountId>;
+    type FrameConfig = Test;
+
+    fn university_info(university_id: &Self::UniversityId) -> Option<Self::UniversityInfo> {
+        (university_id == &UNIVERSITY).then(|| University {
+            admin: UNIVERSITY_ADMIN,
+            permanent_info: ipfs_link(),
+        })
+    }
+
+    fn university_admin(university_id: &Self::UniversityId) -> Option<AccountId> {
+        Self::university_info(university_id).map(|info| info.admin)
+    }
+
+    fn note_affiliation(_: &Self::UniversityId, _: &ProfessorId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn note_disaffiliation(_: &Self::UniversityId, _: &ProfessorId) {}
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register_university_for_benchmark(_: AccountId) -> Self::UniversityId {
+        UNIVERSITY
+    }
+}
+
+pub struct MockProfessorProvider;
+impl ProfessorProvider for MockProfessorProvider {
+    type ProfessorId = ProfessorId;
+    type ProfessorInfo = ProfessorInfo<AccountId>;
+    type AffiliationInfo = ();
+    type FrameConfig = Test;
+
+    fn professor_info(professor_id: &Self::ProfessorId) -> Option<Self::ProfessorInfo> {
+        (professor_id == &PROFESSOR).then(|| ProfessorInfo {
+            professor: PROFESSOR_ACCOUNT,
+            info: ipfs_link(),
+        })
+    }
+
+    fn affiliation_info(_: &Self::ProfessorId, _: &UniversityId) -> Option<()> {
+        None
+    }
+
+    fn is_affiliated(_: &Self::ProfessorId, _: &UniversityId) -> bool {
+        false
+    }
+
+    fn make_offer(_: &AccountId, _: NewOfferParam<u64>) -> Result<OfferId, DispatchError> {
+        Err(DispatchError::Other("no professor"))
+    }
+}
+
+pub struct MockStudentProvider;
+impl StudentProvider for MockStudentProvider {
+    type StudentId = UniqId;
+    type StudentInfo = ();
+    type ApplicationId = UniqId;
+    type ApplicationInfo = ();
+    type FrameConfig = Test;
+
+    fn student_info(_: &Self::StudentId) -> Option<()> {
+        None
+    }
+
+    fn student_of(_: &AccountId) -> Option<Self::StudentId> {
+        None
+    }
+
+    fn note_enrollment(_: &Self::StudentId, _: &UniversityId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn note_graduation(_: &Self::StudentId, _: &UniversityId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn note_withdrawal(_: &Self::StudentId, _: &UniversityId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn application_info(_: &Self::ApplicationId) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("no application"))
+    }
+
+    fn close_application(_: &Self::ApplicationId) -> DispatchResult {
+        Err(DispatchError::Other("no application"))
+    }
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (VALIDATOR_ADMIN, INITIAL_BALANCE),
+            (OTHER_ACCOUNT, INITIAL_BALANCE),
+        ],
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = storage.into();
+    // events are not deposited on genesis block
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
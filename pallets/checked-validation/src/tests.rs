@@ -0,0 +1,798 @@
+use crate::{
+    mock::*, Error, Event, ExpiryCursor, ProfessorValidators, Professors, RetiredValidators,
+    Universities, ValidationExpiryLookup, ValidatorDeposits, Validators,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchError, DispatchResult},
+    traits::{Hooks, ReservableCurrency},
+};
+use traits::pallet_provider::ValidationProvider;
+use types::{
+    professor::ProfessorId,
+    university::UniversityId,
+    validator::{
+        AccreditationLevel, NewValidatorParam, ValidateProfessorParam, ValidateUniversityParam,
+        ValidationTarget, ValidationTerms, ValidatorId, ValidatorTier,
+    },
+};
+
+const VALIDITY: u64 = 10;
+const OTHER_VALIDATOR: ValidatorId = types::primitives::UniqId::new([5; 32]);
+
+fn terms(validity: u64) -> ValidationTerms<u64> {
+    ValidationTerms {
+        evidence: ipfs_link(),
+        level: AccreditationLevel::Full,
+        validity,
+        scope: None,
+    }
+}
+
+fn run_to_block(block_number: u64) {
+    while System::block_number() < block_number {
+        System::set_block_number(System::block_number() + 1);
+        CheckedValidation::on_initialize(System::block_number());
+    }
+}
+
+fn register_validator() {
+    assert_ok!(CheckedValidation::register_validator(
+        RuntimeOrigin::signed(VALIDATOR_ADMIN),
+        Some(VALIDATOR),
+        NewValidatorParam {
+            document: ipfs_link()
+        },
+    ));
+}
+
+fn validate_university(origin: RuntimeOrigin, university: UniversityId) -> DispatchResult {
+    CheckedValidation::validate_university(
+        origin,
+        VALIDATOR,
+        ValidateUniversityParam {
+            university,
+            terms: terms(VALIDITY),
+        },
+    )
+}
+
+fn validate_professor(origin: RuntimeOrigin, professor: ProfessorId) -> DispatchResult {
+    CheckedValidation::validate_professor(
+        origin,
+        VALIDATOR,
+        ValidateProfessorParam {
+            professor,
+            terms: terms(VALIDITY),
+        },
+    )
+}
+
+#[test]
+fn register_validator_works() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        let validator = Validators::<Test>::get(VALIDATOR).expect("validator is registered");
+        assert_eq!(validator.admin, VALIDATOR_ADMIN);
+        System::assert_last_event(Event::ValidatorAdded(VALIDATOR).into());
+    });
+}
+
+#[test]
+fn register_validator_assigns_id() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CheckedValidation::register_validator(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            None,
+            NewValidatorParam {
+                document: ipfs_link()
+            },
+        ));
+        assert_ok!(CheckedValidation::register_validator(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            None,
+            NewValidatorParam {
+                document: ipfs_link()
+            },
+        ));
+
+        // each registration is assigned a fresh id
+        assert_eq!(Validators::<Test>::iter().count(), 2);
+    });
+}
+
+#[test]
+fn register_validator_rejects_unsigned_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CheckedValidation::register_validator(
+                RuntimeOrigin::none(),
+                Some(VALIDATOR),
+                NewValidatorParam {
+                    document: ipfs_link()
+                },
+            ),
+            Error::<Test>::InsufficientPermission
+        );
+    });
+}
+
+#[test]
+fn register_validator_rejects_existing_id() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        assert_noop!(
+            CheckedValidation::register_validator(
+                RuntimeOrigin::signed(OTHER_ACCOUNT),
+                Some(VALIDATOR),
+                NewValidatorParam {
+                    document: ipfs_link()
+                },
+            ),
+            Error::<Test>::ValidatorAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn validate_university_works() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        assert_ok!(validate_university(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            UNIVERSITY
+        ));
+
+        assert!(Universities::<Test>::contains_key(VALIDATOR, UNIVERSITY));
+        System::assert_last_event(
+            Event::UniversityValidated {
+                validated_by: VALIDATOR,
+                university: UNIVERSITY,
+                level: AccreditationLevel::Full,
+                valid_until: 1 + VALIDITY,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn validate_university_requires_validator() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            validate_university(RuntimeOrigin::signed(VALIDATOR_ADMIN), UNIVERSITY),
+            Error::<Test>::ValidatorDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn validate_university_requires_validator_admin() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        assert_noop!(
+            validate_university(RuntimeOrigin::signed(OTHER_ACCOUNT), UNIVERSITY),
+            Error::<Test>::InsufficientPermission
+        );
+        assert_noop!(
+            validate_university(RuntimeOrigin::none(), UNIVERSITY),
+            Error::<Test>::InsufficientPermission
+        );
+    });
+}
+
+#[test]
+fn validate_university_requires_existing_university() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        assert_noop!(
+            validate_university(RuntimeOrigin::signed(VALIDATOR_ADMIN), UNKNOWN),
+            Error::<Test>::NoUniversity
+        );
+    });
+}
+
+#[test]
+fn validate_university_rejects_duplicate() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        assert_ok!(validate_university(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            UNIVERSITY
+        ));
+
+        assert_noop!(
+            validate_university(RuntimeOrigin::signed(VALIDATOR_ADMIN), UNIVERSITY),
+            Error::<Test>::UniversityAlreadyValidated
+        );
+    });
+}
+
+#[test]
+fn validate_professor_works() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        assert_ok!(validate_professor(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            PROFESSOR
+        ));
+
+        assert!(Professors::<Test>::contains_key(VALIDATOR, PROFESSOR));
+        System::assert_last_event(
+            Event::ProfessorValidated {
+                validated_by: VALIDATOR,
+                professor: PROFESSOR,
+                level: AccreditationLevel::Full,
+                valid_until: 1 + VALIDITY,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn validate_professor_requires_validator() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            validate_professor(RuntimeOrigin::signed(VALIDATOR_ADMIN), PROFESSOR),
+            Error::<Test>::ValidatorDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn validate_professor_requires_validator_admin() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        assert_noop!(
+            validate_professor(RuntimeOrigin::signed(OTHER_ACCOUNT), PROFESSOR),
+            Error::<Test>::InsufficientPermission
+        );
+    });
+}
+
+#[test]
+fn validate_professor_requires_existing_professor() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        assert_noop!(
+            validate_professor(RuntimeOrigin::signed(VALIDATOR_ADMIN), UNKNOWN),
+            Error::<Test>::NoProfessor
+        );
+    });
+}
+
+#[test]
+fn validate_professor_rejects_duplicate() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        assert_ok!(validate_professor(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            PROFESSOR
+        ));
+
+        assert_noop!(
+            validate_professor(RuntimeOrigin::signed(VALIDATOR_ADMIN), PROFESSOR),
+            Error::<Test>::ProfessorAlreadyValidated
+        );
+    });
+}
+
+#[test]
+fn validate_rejects_invalid_validity() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        for validity in [0, MAX_VALIDITY + 1] {
+            assert_noop!(
+                CheckedValidation::validate_university(
+                    RuntimeOrigin::signed(VALIDATOR_ADMIN),
+                    VALIDATOR,
+                    ValidateUniversityParam {
+                        university: UNIVERSITY,
+                        terms: terms(validity),
+                    },
+                ),
+                Error::<Test>::InvalidValidity
+            );
+        }
+    });
+}
+
+#[test]
+fn revoke_validation_works() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        assert_ok!(validate_university(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            UNIVERSITY
+        ));
+
+        assert_ok!(CheckedValidation::revoke_validation(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            VALIDATOR,
+            ValidationTarget::University(UNIVERSITY),
+        ));
+
+        assert!(!Universities::<Test>::contains_key(VALIDATOR, UNIVERSITY));
+        assert_eq!(ValidationExpiryLookup::<Test>::iter().count(), 0);
+        System::assert_last_event(
+            Event::ValidationRevoked {
+                validated_by: VALIDATOR,
+                target: ValidationTarget::University(UNIVERSITY),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn revoke_validation_requires_validation() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        assert_noop!(
+            CheckedValidation::revoke_validation(
+                RuntimeOrigin::signed(VALIDATOR_ADMIN),
+                VALIDATOR,
+                ValidationTarget::Professor(PROFESSOR),
+            ),
+            Error::<Test>::NotValidated
+        );
+    });
+}
+
+#[test]
+fn revoke_validation_requires_validator_admin() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        assert_ok!(validate_professor(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            PROFESSOR
+        ));
+
+        assert_noop!(
+            CheckedValidation::revoke_validation(
+                RuntimeOrigin::signed(OTHER_ACCOUNT),
+                VALIDATOR,
+                ValidationTarget::Professor(PROFESSOR),
+            ),
+            Error::<Test>::InsufficientPermission
+        );
+    });
+}
+
+#[test]
+fn renew_validation_works() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        assert_ok!(validate_professor(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            PROFESSOR
+        ));
+        run_to_block(5);
+
+        let renewed_terms = ValidationTerms {
+            level: AccreditationLevel::Conditional,
+            ..terms(20)
+        };
+        assert_ok!(CheckedValidation::renew_validation(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            VALIDATOR,
+            ValidationTarget::Professor(PROFESSOR),
+            renewed_terms,
+        ));
+
+        let record = Professors::<Test>::get(VALIDATOR, PROFESSOR).expect("still validated");
+        assert_eq!(record.level, AccreditationLevel::Conditional);
+        assert_eq!((record.valid_from, record.valid_until), (5, 25));
+        // only the renewed expiry is scheduled
+        assert_eq!(
+            ValidationExpiryLookup::<Test>::iter_keys()
+                .map(|(block_number, _)| block_number)
+                .collect::<Vec<_>>(),
+            vec![25]
+        );
+        System::assert_last_event(
+            Event::ValidationRenewed {
+                validated_by: VALIDATOR,
+                target: ValidationTarget::Professor(PROFESSOR),
+                level: AccreditationLevel::Conditional,
+                valid_until: 25,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn renew_validation_requires_validation() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        assert_noop!(
+            CheckedValidation::renew_validation(
+                RuntimeOrigin::signed(VALIDATOR_ADMIN),
+                VALIDATOR,
+                ValidationTarget::University(UNIVERSITY),
+                terms(VALIDITY),
+            ),
+            Error::<Test>::NotValidated
+        );
+    });
+}
+
+#[test]
+fn renew_validation_rejects_invalid_validity() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        assert_ok!(validate_university(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            UNIVERSITY
+        ));
+
+        assert_noop!(
+            CheckedValidation::renew_validation(
+                RuntimeOrigin::signed(VALIDATOR_ADMIN),
+                VALIDATOR,
+                ValidationTarget::University(UNIVERSITY),
+                terms(0),
+            ),
+            Error::<Test>::InvalidValidity
+        );
+    });
+}
+
+#[test]
+fn validation_expires_on_valid_until() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        assert_ok!(validate_university(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            UNIVERSITY
+        ));
+
+        run_to_block(VALIDITY);
+        assert!(CheckedValidation::is_verified_university(
+            &VALIDATOR,
+            &UNIVERSITY
+        ));
+
+        run_to_block(1 + VALIDITY);
+        assert!(!CheckedValidation::is_verified_university(
+            &VALIDATOR,
+            &UNIVERSITY
+        ));
+        assert!(!Universities::<Test>::contains_key(VALIDATOR, UNIVERSITY));
+        System::assert_last_event(
+            Event::ValidationExpired {
+                validated_by: VALIDATOR,
+                target: ValidationTarget::University(UNIVERSITY),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn expiries_over_the_limit_are_carried_over() {
+    new_test_ext().execute_with(|| {
+        // three validations expiring on same block with a limit of two per block
+        for validator in 0..3_u8 {
+            let validator_id = types::primitives::UniqId::new([validator; 32]);
+            assert_ok!(CheckedValidation::register_validator(
+                RuntimeOrigin::signed(VALIDATOR_ADMIN),
+                Some(validator_id),
+                NewValidatorParam {
+                    document: ipfs_link()
+                },
+            ));
+            assert_ok!(CheckedValidation::validate_university(
+                RuntimeOrigin::signed(VALIDATOR_ADMIN),
+                validator_id,
+                ValidateUniversityParam {
+                    university: UNIVERSITY,
+                    terms: terms(VALIDITY),
+                },
+            ));
+        }
+
+        run_to_block(1 + VALIDITY);
+        assert_eq!(Universities::<Test>::iter().count(), 1);
+        assert_eq!(ExpiryCursor::<Test>::get(), Some(1 + VALIDITY));
+
+        run_to_block(2 + VALIDITY);
+        assert_eq!(Universities::<Test>::iter().count(), 0);
+        assert_eq!(ExpiryCursor::<Test>::get(), None);
+    });
+}
+
+#[test]
+fn trust_set_queries_work() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        assert_ok!(validate_university(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            UNIVERSITY
+        ));
+        assert_ok!(validate_professor(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            PROFESSOR
+        ));
+
+        assert_eq!(
+            CheckedValidation::university_validators(&UNIVERSITY),
+            vec![VALIDATOR]
+        );
+        assert_eq!(
+            CheckedValidation::professor_validators(&PROFESSOR),
+            vec![VALIDATOR]
+        );
+        assert!(CheckedValidation::is_university_trusted_by(
+            &UNIVERSITY,
+            &[UNKNOWN, VALIDATOR]
+        ));
+        assert!(!CheckedValidation::is_university_trusted_by(
+            &UNIVERSITY,
+            &[UNKNOWN]
+        ));
+        assert!(!CheckedValidation::is_professor_trusted_by(&PROFESSOR, &[]));
+
+        assert_ok!(CheckedValidation::revoke_validation(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            VALIDATOR,
+            ValidationTarget::University(UNIVERSITY),
+        ));
+        assert!(CheckedValidation::university_validators(&UNIVERSITY).is_empty());
+        assert!(!CheckedValidation::is_university_trusted_by(
+            &UNIVERSITY,
+            &[VALIDATOR]
+        ));
+
+        run_to_block(1 + VALIDITY);
+        assert!(CheckedValidation::professor_validators(&PROFESSOR).is_empty());
+        assert_eq!(ProfessorValidators::<Test>::iter().count(), 0);
+    });
+}
+
+fn register_open_validator(validator_id: ValidatorId, admin: AccountId) {
+    assert_ok!(CheckedValidation::register_validator(
+        RuntimeOrigin::signed(admin),
+        Some(validator_id),
+        NewValidatorParam {
+            document: ipfs_link()
+        },
+    ));
+}
+
+#[test]
+fn register_validator_reserves_deposit() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        assert_eq!(
+            Balances::reserved_balance(VALIDATOR_ADMIN),
+            VALIDATOR_DEPOSIT
+        );
+        assert_eq!(
+            ValidatorDeposits::<Test>::get(VALIDATOR),
+            Some((VALIDATOR_ADMIN, VALIDATOR_DEPOSIT))
+        );
+        assert_eq!(
+            CheckedValidation::validator_tier(&VALIDATOR),
+            Some(ValidatorTier::Open)
+        );
+    });
+}
+
+#[test]
+fn register_validator_requires_deposit() {
+    new_test_ext().execute_with(|| {
+        // university admin holds no balance
+        assert_noop!(
+            CheckedValidation::register_validator(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                Some(VALIDATOR),
+                NewValidatorParam {
+                    document: ipfs_link()
+                },
+            ),
+            pallet_balances::Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn deregister_validator_returns_deposit() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        assert_ok!(validate_university(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            UNIVERSITY
+        ));
+
+        assert_ok!(CheckedValidation::deregister_validator(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            VALIDATOR
+        ));
+
+        assert!(!Validators::<Test>::contains_key(VALIDATOR));
+        assert_eq!(Balances::reserved_balance(VALIDATOR_ADMIN), 0);
+        assert_eq!(CheckedValidation::validator_tier(&VALIDATOR), None);
+        // validations of removed validator are no longer reported
+        assert!(!CheckedValidation::is_verified_university(
+            &VALIDATOR,
+            &UNIVERSITY
+        ));
+        System::assert_last_event(Event::ValidatorRemoved(VALIDATOR).into());
+    });
+}
+
+#[test]
+fn deregistered_validator_id_cannot_be_reused() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        assert_ok!(validate_university(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            UNIVERSITY
+        ));
+        assert_ok!(CheckedValidation::deregister_validator(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            VALIDATOR
+        ));
+
+        assert_noop!(
+            CheckedValidation::register_validator(
+                RuntimeOrigin::signed(OTHER_ACCOUNT),
+                Some(VALIDATOR),
+                NewValidatorParam {
+                    document: ipfs_link(),
+                },
+            ),
+            Error::<Test>::ValidatorRetired
+        );
+        assert!(RetiredValidators::<Test>::contains_key(VALIDATOR));
+        // validations granted by the old validator are not inherited
+        assert!(!CheckedValidation::is_verified_university(
+            &VALIDATOR,
+            &UNIVERSITY
+        ));
+        assert!(CheckedValidation::validated_university_info(&VALIDATOR, &UNIVERSITY).is_none());
+        assert!(CheckedValidation::university_validators(&UNIVERSITY).is_empty());
+    });
+}
+
+#[test]
+fn set_root_validator_requires_root() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+
+        assert_noop!(
+            CheckedValidation::set_root_validator(
+                RuntimeOrigin::signed(VALIDATOR_ADMIN),
+                VALIDATOR
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            CheckedValidation::set_root_validator(RuntimeOrigin::root(), OTHER_VALIDATOR),
+            Error::<Test>::ValidatorDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn set_root_validator_replaces_previous_root() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        register_open_validator(OTHER_VALIDATOR, OTHER_ACCOUNT);
+
+        assert_ok!(CheckedValidation::set_root_validator(
+            RuntimeOrigin::root(),
+            VALIDATOR
+        ));
+        assert_eq!(CheckedValidation::root_validator(), Some(VALIDATOR));
+        assert_eq!(
+            CheckedValidation::validator_tier(&VALIDATOR),
+            Some(ValidatorTier::Root)
+        );
+        System::assert_last_event(Event::RootValidatorSet(VALIDATOR).into());
+
+        assert_ok!(CheckedValidation::set_root_validator(
+            RuntimeOrigin::root(),
+            OTHER_VALIDATOR
+        ));
+        assert_eq!(CheckedValidation::root_validator(), Some(OTHER_VALIDATOR));
+        assert_eq!(
+            CheckedValidation::validator_tier(&VALIDATOR),
+            Some(ValidatorTier::Accredited)
+        );
+    });
+}
+
+#[test]
+fn root_validator_cannot_be_deregistered() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        assert_ok!(CheckedValidation::set_root_validator(
+            RuntimeOrigin::root(),
+            VALIDATOR
+        ));
+
+        assert_noop!(
+            CheckedValidation::deregister_validator(
+                RuntimeOrigin::signed(VALIDATOR_ADMIN),
+                VALIDATOR
+            ),
+            Error::<Test>::IsRootValidator
+        );
+    });
+}
+
+#[test]
+fn accreditation_works() {
+    new_test_ext().execute_with(|| {
+        register_validator();
+        register_open_validator(OTHER_VALIDATOR, OTHER_ACCOUNT);
+        assert_noop!(
+            CheckedValidation::accredit_validator(
+                RuntimeOrigin::signed(VALIDATOR_ADMIN),
+                OTHER_VALIDATOR
+            ),
+            Error::<Test>::NoRootValidator
+        );
+        assert_ok!(CheckedValidation::set_root_validator(
+            RuntimeOrigin::root(),
+            VALIDATOR
+        ));
+
+        assert_noop!(
+            CheckedValidation::accredit_validator(
+                RuntimeOrigin::signed(OTHER_ACCOUNT),
+                OTHER_VALIDATOR
+            ),
+            Error::<Test>::InsufficientPermission
+        );
+        assert_ok!(CheckedValidation::accredit_validator(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            OTHER_VALIDATOR
+        ));
+        assert_eq!(
+            CheckedValidation::validator_tier(&OTHER_VALIDATOR),
+            Some(ValidatorTier::Accredited)
+        );
+        System::assert_last_event(Event::ValidatorAccredited(OTHER_VALIDATOR).into());
+        assert_noop!(
+            CheckedValidation::accredit_validator(
+                RuntimeOrigin::signed(VALIDATOR_ADMIN),
+                OTHER_VALIDATOR
+            ),
+            Error::<Test>::AlreadyAccredited
+        );
+
+        assert_ok!(CheckedValidation::revoke_accreditation(
+            RuntimeOrigin::signed(VALIDATOR_ADMIN),
+            OTHER_VALIDATOR
+        ));
+        assert_eq!(
+            CheckedValidation::validator_tier(&OTHER_VALIDATOR),
+            Some(ValidatorTier::Open)
+        );
+        System::assert_last_event(Event::AccreditationRevoked(OTHER_VALIDATOR).into());
+        assert_noop!(
+            CheckedValidation::revoke_accreditation(
+                RuntimeOrigin::signed(VALIDATOR_ADMIN),
+                OTHER_VALIDATOR
+            ),
+            Error::<Test>::NotAccredited
+        );
+    });
+}
@@ -6,8 +6,10 @@ pub use pallet::*;
 pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
+    use pallet_provider_traits::{LectureProvider, ProfessorProvider, UniversityProvider};
     use traits::pallet_provider as pallet_provider_traits;
-    use types::{exam::*, professor::ProfessorId, university::UniversityId};
+    pub(super) use types::exam::*;
+    use types::{lecture::LectureId, professor::ProfessorId, university::UniversityId};
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
@@ -17,22 +19,39 @@ pub mod pallet {
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-        type UniversityProvider: pallet_provider_traits::UniversityProvider;
-        type ProfessorProvider: pallet_provider_traits::ProfessorProvider;
+        type UniversityProvider: UniversityProvider<UniversityId = UniversityId, FrameConfig = Self>;
+        type ProfessorProvider: ProfessorProvider<ProfessorId = ProfessorId>;
         type StudentProvider: pallet_provider_traits::StudentProvider;
-        type LectureProvider: pallet_provider_traits::LectureProvider;
+        type LectureProvider: LectureProvider<
+            LectureId = LectureId,
+            LectureInfo = types::lecture::Lecture,
+        >;
     }
 
     #[pallet::storage]
-    #[pallet::getter(fn something)]
-    pub type Something<T> = StorageValue<_, u32>;
+    #[pallet::getter(fn get_exam)]
+    pub type Exams<T> = StorageMap<_, Twox64Concat, ExamId, Exam>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {}
+    pub enum Event<T: Config> {
+        /// New exam have been created
+        ExamCreated(ExamId),
+    }
 
     #[pallet::error]
-    pub enum Error<T> {}
+    pub enum Error<T> {
+        /// Origin cannot perform this action
+        InsufficientPermission,
+        /// No university with given id exists
+        NoUniversity,
+        /// Professor does not exists
+        NoProfessor,
+        /// Lecture does not exists in this university
+        NoLecture,
+        /// Exam with this id already exists
+        ExamExists,
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -52,6 +71,33 @@ pub mod pallet {
             // TODO:
             // find a way to submit the questionarries but hide it till the
             // exam starts
+            let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let university_admin = T::UniversityProvider::university_admin(&university)
+                .ok_or(Error::<T>::NoUniversity)?;
+            ensure!(
+                signer == university_admin,
+                Error::<T>::InsufficientPermission
+            );
+            T::ProfessorProvider::professor_info(&professor).ok_or(Error::<T>::NoProfessor)?;
+            ensure!(!Exams::<T>::contains_key(&exam_id), Error::<T>::ExamExists);
+
+            let NewExamParam { lecture, details } = exam_details;
+            // exam can only be taken for lecture of same university
+            if let Some(lecture_id) = &lecture {
+                let lecture_info =
+                    T::LectureProvider::lecture_info(lecture_id).ok_or(Error::<T>::NoLecture)?;
+                ensure!(lecture_info.university == university, Error::<T>::NoLecture);
+            }
+
+            let exam = Exam {
+                university,
+                professor,
+                lecture,
+                details,
+            };
+
+            Exams::<T>::insert(&exam_id, exam);
+            Self::deposit_event(Event::<T>::ExamCreated(exam_id));
 
             Ok(())
         }
@@ -59,7 +105,10 @@ pub mod pallet {
 }
 
 impl<T: Config> traits::pallet_provider::ExamProvider for Pallet<T> {
-    fn get_exam_info() {
-        todo!()
+    type ExamId = crate::ExamId;
+    type ExamInfo = crate::Exam;
+
+    fn exam_info(exam_id: &Self::ExamId) -> Option<Self::ExamInfo> {
+        crate::Pallet::<T>::get_exam(exam_id)
     }
 }
@@ -0,0 +1,80 @@
+//! Benchmarking setup for pallet-intake
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+    sp_runtime::traits::{One, Zero},
+    traits::Get,
+    BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_std::vec::Vec;
+use traits::pallet_provider::UniversityProvider;
+use types::primitives::StdIpfsLink;
+
+/// announce a closed intake of a new university with `applicants` number of applications
+/// return the university admin, intake and the applied students
+fn setup_closed_intake<T: Config>(
+    applicants: u32,
+) -> (T::AccountId, IntakeIdOf<T>, Vec<T::AccountId>) {
+    let caller: T::AccountId = whitelisted_caller();
+    let university_id = T::UniversityProvider::register_university_for_benchmark(caller);
+    // provider may register the university under it's own admin
+    let admin = T::UniversityProvider::university_admin(&university_id)
+        .expect("university is registered for benchmark");
+    let intake_id = IntakeId {
+        university_id,
+        intake_index: 0,
+    };
+
+    Intakes::<T>::insert(
+        &intake_id,
+        IntakeInfo {
+            application_opens: Zero::zero(),
+            application_closes: One::one(),
+            max_applicants: applicants,
+            max_accepted: applicants,
+            status: IntakeStatus::IntakeClosed,
+            admission_policy: AdmissionPolicy::Manual,
+            programme: None,
+        },
+    );
+
+    let students = (0..applicants)
+        .map(|index| {
+            let student: T::AccountId = account("student", index, 0);
+            let application = IntakeApplication {
+                applied_on: Zero::zero(),
+                info: StdIpfsLink::new(Default::default()),
+            };
+            Applications::<T>::insert(&intake_id, &student, application);
+            student
+        })
+        .collect();
+    IntakeCounters::<T>::mutate(&intake_id, |counter| counter.applicants = applicants);
+
+    (admin, intake_id, students)
+}
+
+benchmarks! {
+    accept_application {
+        let (admin, intake_id, mut students) = setup_closed_intake::<T>(1);
+        let student = students.remove(0);
+    }: _(RawOrigin::Signed(admin), intake_id.clone(), student.clone())
+    verify {
+        assert!(AcceptedApplications::<T>::contains_key(&intake_id, &student));
+    }
+
+    accept_applications {
+        let n in 1 .. T::MaxAcceptApplications::get();
+        let (admin, intake_id, students) = setup_closed_intake::<T>(n);
+        let students = BoundedVec::<_, T::MaxAcceptApplications>::try_from(students)
+            .expect("n is within MaxAcceptApplications");
+    }: _(RawOrigin::Signed(admin), intake_id.clone(), students)
+    verify {
+        assert_eq!(IntakeCounters::<T>::get(&intake_id).accepted, n);
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -2,14 +2,41 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::{pallet_prelude::*, Twox64Concat};
+    use crate::WeightInfo;
+    use codec::FullCodec;
+    use frame_support::{
+        pallet_prelude::*,
+        sp_runtime::traits::{Hash, One, Saturating},
+        storage::IterableStorageDoubleMap,
+        traits::Randomness,
+        Twox64Concat,
+    };
     use frame_system::pallet_prelude::*;
-    use pallet_provider_traits::UniversityProvider;
-    use traits::pallet_provider as pallet_provider_traits;
+    use pallet_provider_traits::{
+        EnrollmentProvider, StudentProvider, UniversityProvider, ValidationProvider,
+    };
+    use sp_std::vec::Vec;
+    use traits::{pagination::double_map_page, pallet_provider as pallet_provider_traits};
     pub(super) use types::intake::*;
-    use types::{primitives::*, university::UniversityId, BlockNumberOf};
+    use types::{
+        primitives::*,
+        university::{EnrollmentSource, UniversityId},
+        BlockNumberOf,
+    };
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
@@ -24,6 +51,63 @@ pub mod pallet {
             UniversityId = types::university::UniversityId,
             FrameConfig = Self,
         >;
+        /// Resolves the student profile of an applicant when it confirms the offer
+        type StudentProvider: pallet_provider_traits::StudentProvider<
+            StudentId = types::student::StudentId,
+            FrameConfig = Self,
+        >;
+        /// Students who confirm their offer are enrolled through this provider
+        type EnrollmentProvider: pallet_provider_traits::EnrollmentProvider<
+            UniversityId = types::university::UniversityId,
+            StudentId = types::student::StudentId,
+        >;
+        type ValidationProvider: pallet_provider_traits::ValidationProvider<
+            ValidatorId = types::validator::ValidatorId,
+            UniversityId = types::university::UniversityId,
+        >;
+
+        /// Validators at least one of which must have validated a university
+        /// before it can announce an intake. Empty set disables the requirement
+        type TrustedValidators: Get<Vec<types::validator::ValidatorId>>;
+
+        /// Maximum number of intakes that can be opened or closed by scheduler in a single block
+        /// remaining transitions are carried over to next block
+        #[pallet::constant]
+        type MaxIntakeTransitionsPerBlock: Get<u32>;
+
+        /// Maximum number of applications that can wait for a seat
+        /// after an intake reached it's `max_applicants`
+        #[pallet::constant]
+        type MaxWaitlistLength: Get<u32>;
+
+        /// Maximum number of applications that can be accepted in a single call
+        #[pallet::constant]
+        type MaxAcceptApplications: Get<AcceptApplicationLimit>;
+
+        /// Maximum number of applicants an intake can have to use automatic admission
+        /// keeps the admission done in a single block bounded
+        #[pallet::constant]
+        type MaxAutoAdmissionApplicants: Get<u32>;
+
+        /// Maximum number of applications and scores of finalised intakes removed in a single block
+        /// remaining ones are removed in next blocks
+        #[pallet::constant]
+        type MaxApplicationRemovalsPerBlock: Get<u32>;
+
+        /// Number of blocks an accepted applicant have to respond to the offer
+        #[pallet::constant]
+        type OfferResponsePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Source of randomness for lottery based admission
+        ///
+        /// lottery is only as fair as this source. Block hash based sources such as
+        /// `pallet_randomness_collective_flip` can be predicted and biased by block authors
+        /// so are only suitable for test networks, production runtime should use a VRF based
+        /// source like BABE's `RandomnessFromOneEpochAgo`
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: WeightInfo;
     }
 
     type StudentIdOf<T> = AccountIdOf<T>;
@@ -31,6 +115,10 @@ pub mod pallet {
         <<T as Config>::UniversityProvider as UniversityProvider>::UniversityId;
     pub(crate) type IntakeIdOf<T> = IntakeId<UniversityIdOf<T>>;
     pub(crate) type IntakeApplicationOf<T> = IntakeApplication<BlockNumberFor<T>>;
+    pub(crate) type AdmissionOfferOf<T> = AdmissionOffer<BlockNumberFor<T>>;
+    pub(crate) type WaitlistEntryOf<T> = WaitlistEntry<StudentIdOf<T>, BlockNumberFor<T>>;
+    pub(crate) type WaitlistOf<T> =
+        BoundedVec<WaitlistEntryOf<T>, <T as Config>::MaxWaitlistLength>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -45,6 +133,36 @@ pub mod pallet {
         ApplicationAccepted(IntakeIdOf<T>, StudentIdOf<T>),
         /// An intake have been finalised
         IntakeFinalised(IntakeIdOf<T>),
+        /// All applications of finalised intake have been removed
+        IntakeCleanedUp(IntakeIdOf<T>),
+        /// Intake have been opened for application by scheduler
+        IntakeOpened(IntakeIdOf<T>),
+        /// Intake have been closed for application by scheduler
+        IntakeClosed(IntakeIdOf<T>),
+        /// Intake reached it's applicant cap and application is put in waitlist
+        AddedToWaitlist(IntakeIdOf<T>, StudentIdOf<T>),
+        /// Waitlisted application have been promoted to a regular application
+        PromotedFromWaitlist(IntakeIdOf<T>, StudentIdOf<T>),
+        /// Batch of applications have been processed
+        /// `failed` contains the applications that could not be accepted with the reason
+        ApplicationsBatchAccepted {
+            intake_id: IntakeIdOf<T>,
+            accepted: u32,
+            failed: Vec<(StudentIdOf<T>, DispatchError)>,
+        },
+        /// University gave a score to an application
+        ApplicationScored(IntakeIdOf<T>, StudentIdOf<T>, AdmissionScore),
+        /// Chain admitted the applicants of intake according to it's admission policy
+        AutomaticAdmissionCompleted {
+            intake_id: IntakeIdOf<T>,
+            accepted: u32,
+        },
+        /// Accepted applicant confirmed the enrollment
+        EnrollmentConfirmed(IntakeIdOf<T>, StudentIdOf<T>),
+        /// Accepted applicant declined the offer and seat is released
+        OfferDeclined(IntakeIdOf<T>, StudentIdOf<T>),
+        /// Accepted applicant did not respond in time and seat is released
+        OfferLapsed(IntakeIdOf<T>, StudentIdOf<T>),
     }
 
     #[pallet::error]
@@ -67,24 +185,78 @@ pub mod pallet {
         NonExistentApplication,
         /// Intake is not closed
         IntakeNotClosed,
+        /// Intake reached it's applicant cap and waitlist is also full
+        IntakeFull,
+        /// Intake already accepted `max_accepted` applications
+        AcceptanceQuotaReached,
+        /// Action is not supported by the admission policy of this intake
+        InvalidAdmissionPolicy,
+        /// Automatic admission of this intake is not yet done
+        AdmissionPending,
+        /// No offer waiting for response from this applicant
+        NoPendingOffer,
+        /// Response period of the offer is over
+        OfferExpired,
+        /// Some accepted applicants are yet to respond to their offer
+        OffersPending,
+        /// University is not validated by any of the `TrustedValidators`
+        UniversityNotValidated,
+        /// Account is not registered as student
+        NoStudent,
     }
 
     #[pallet::storage]
     #[pallet::getter(fn get_intake)]
     pub type Intakes<T> = StorageMap<_, Twox64Concat, IntakeIdOf<T>, IntakeInfo<BlockNumberOf<T>>>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn get_intake_opening_date)]
+    pub type IntakeOpeningDateLookup<T> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberOf<T>, Twox64Concat, IntakeIdOf<T>, ()>;
+
     #[pallet::storage]
     #[pallet::getter(fn get_intake_closing_date)]
     pub type IntakeClosingDateLookup<T> =
         StorageDoubleMap<_, Twox64Concat, BlockNumberOf<T>, Twox64Concat, IntakeIdOf<T>, ()>;
 
+    /// Earliest block whose scheduled intake transitions are not yet fully processed
+    /// None when scheduler is up to date with current block
+    #[pallet::storage]
+    #[pallet::getter(fn get_scheduler_cursor)]
+    pub type SchedulerCursor<T> = StorageValue<_, BlockNumberOf<T>>;
+
     #[pallet::storage]
     pub type LastUniIntake<T> = StorageMap<_, Twox64Concat, UniversityIdOf<T>, IntakeIdOf<T>>;
 
+    /// Index of intakes announced by a university
+    /// second key is the `intake_index` of intake
+    #[pallet::storage]
+    pub type UniversityIntakes<T> =
+        StorageDoubleMap<_, Twox64Concat, UniversityIdOf<T>, Twox64Concat, u32, ()>;
+
+    /// Accepted applications with the offer made to the applicant
     #[pallet::storage]
     #[pallet::getter(fn get_accepted_application)]
-    pub type AcceptedApplications<T> =
-        StorageDoubleMap<_, Twox64Concat, IntakeIdOf<T>, Twox64Concat, StudentIdOf<T>, ()>;
+    pub type AcceptedApplications<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        IntakeIdOf<T>,
+        Twox64Concat,
+        StudentIdOf<T>,
+        AdmissionOfferOf<T>,
+    >;
+
+    /// Pending offers keyed by the block they lapse on
+    #[pallet::storage]
+    #[pallet::getter(fn get_offer_expiry)]
+    pub type OfferExpiryLookup<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberOf<T>,
+        Twox64Concat,
+        (IntakeIdOf<T>, StudentIdOf<T>),
+        (),
+    >;
 
     #[pallet::storage]
     pub type Applications<T> = StorageDoubleMap<
@@ -96,6 +268,38 @@ pub mod pallet {
         IntakeApplicationOf<T>,
     >;
 
+    /// Running applicant and acceptance counts of the intake
+    #[pallet::storage]
+    #[pallet::getter(fn get_intake_counter)]
+    pub type IntakeCounters<T> =
+        StorageMap<_, Twox64Concat, IntakeIdOf<T>, IntakeCounter, ValueQuery>;
+
+    /// Applications waiting for a seat in the order they were made
+    #[pallet::storage]
+    #[pallet::getter(fn get_waitlist)]
+    pub type Waitlists<T> = StorageMap<_, Twox64Concat, IntakeIdOf<T>, WaitlistOf<T>, ValueQuery>;
+
+    /// Score given by university to the application of intake with `ScoreBased` admission
+    #[pallet::storage]
+    #[pallet::getter(fn get_application_score)]
+    pub type ApplicationScores<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        IntakeIdOf<T>,
+        Twox64Concat,
+        StudentIdOf<T>,
+        AdmissionScore,
+    >;
+
+    /// Closed intakes waiting for the chain to admit their applicants
+    #[pallet::storage]
+    pub type PendingAdmissions<T> = StorageMap<_, Twox64Concat, IntakeIdOf<T>, ()>;
+
+    /// Finalised intakes whose applications are yet to be removed
+    #[pallet::storage]
+    #[pallet::getter(fn get_pending_cleanup)]
+    pub type PendingCleanups<T> = StorageMap<_, Twox64Concat, IntakeIdOf<T>, IntakeCleanup>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::weight(10_000)]
@@ -116,15 +320,33 @@ pub mod pallet {
             );
             // ensure this student has not applied for same intake before
             ensure!(
-                !Applications::<T>::contains_key(&intake_id, &student_id),
+                !Applications::<T>::contains_key(&intake_id, &student_id)
+                    && !Self::is_waitlisted(&intake_id, &student_id),
                 Error::<T>::InvalidParamater,
             );
 
             // modify the application applied date
             application.applied_on = Self::current_block_number();
 
+            // put the application in waitlist if applicant cap is reached
+            if Self::get_intake_counter(&intake_id).applicants >= intake_info.max_applicants {
+                Waitlists::<T>::try_mutate(&intake_id, |waitlist| {
+                    waitlist.try_push(WaitlistEntry {
+                        student: student_id.clone(),
+                        application,
+                    })
+                })
+                .map_err(|_| Error::<T>::IntakeFull)?;
+
+                Self::deposit_event(Event::AddedToWaitlist(intake_id, student_id));
+                return Ok(());
+            }
+
             // put the application
             Applications::<T>::insert(&intake_id, &student_id, application);
+            IntakeCounters::<T>::mutate(&intake_id, |counter| {
+                counter.applicants = counter.applicants.saturating_add(1)
+            });
 
             // emit event
             Self::deposit_event(Event::AppliedForIntake(intake_id, student_id));
@@ -144,8 +366,9 @@ pub mod pallet {
                 .ok_or(Error::<T>::NonExistentUniversity)?;
 
             // only university can announce new intake
+            // and only for itself
             ensure!(
-                signer == university_admin,
+                signer == university_admin && intake_id.university_id == university_id,
                 Error::<T>::InsufficientPermission
             );
             // intake id must be unique
@@ -153,16 +376,27 @@ pub mod pallet {
                 !Intakes::<T>::contains_key(&intake_id),
                 Error::<T>::IntakeExists
             );
+            // university must be vouched by a trusted validator if runtime requires so
+            let trusted = T::TrustedValidators::get();
+            ensure!(
+                trusted.is_empty()
+                    || T::ValidationProvider::is_university_trusted_by(&university_id, &trusted),
+                Error::<T>::UniversityNotValidated
+            );
 
             let types::intake::NewIntakeParam {
                 application_opens,
                 application_closes,
                 max_accepted,
                 max_applicants,
+                admission_policy,
+                programme,
             } = intake_info;
             // application closing date and opening date should be valid
+            // and closing date should be in future so scheduler can close it
+            let current_block_number = Self::current_block_number();
             ensure!(
-                application_closes > application_opens,
+                application_closes > application_opens && application_closes > current_block_number,
                 Error::<T>::InvalidParamater
             );
             // max_accepted & max_applicants must be greater than 0
@@ -171,13 +405,22 @@ pub mod pallet {
                 max_applicants > 0 && max_accepted > 0 && max_accepted <= max_applicants,
                 Error::<T>::InvalidParamater
             );
+            // automatic admission is done in single block so is bounded
+            ensure!(
+                !admission_policy.is_automatic()
+                    || max_applicants <= T::MaxAutoAdmissionApplicants::get(),
+                Error::<T>::InvalidAdmissionPolicy
+            );
 
             // get the intake status
-            let intake_status = if Self::current_block_number() >= application_opens {
+            // and schedule the opening if it is yet to be opened
+            let intake_status = if current_block_number >= application_opens {
                 IntakeStatus::IntakeOngoing
             } else {
+                IntakeOpeningDateLookup::<T>::insert(&application_opens, &intake_id, ());
                 IntakeStatus::IntakePending
             };
+            IntakeClosingDateLookup::<T>::insert(&application_closes, &intake_id, ());
 
             let intake_info = IntakeInfo {
                 application_opens,
@@ -185,9 +428,12 @@ pub mod pallet {
                 max_applicants,
                 max_accepted,
                 status: intake_status,
+                admission_policy,
+                programme,
             };
             Intakes::<T>::insert(&intake_id, &intake_info);
             LastUniIntake::<T>::insert(&university_id, &intake_id);
+            UniversityIntakes::<T>::insert(&university_id, intake_id.intake_index, ());
 
             Self::deposit_event(Event::<T>::NewIntakeAnnounced(intake_id));
             Ok(())
@@ -202,8 +448,9 @@ pub mod pallet {
                 ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
 
             // ensure the application was applied for
+            let is_applied = Applications::<T>::contains_key(&intake_id, &student_id);
             ensure!(
-                Applications::<T>::contains_key(&intake_id, &student_id),
+                is_applied || Self::is_waitlisted(&intake_id, &student_id),
                 Error::<T>::InvalidParamater,
             );
 
@@ -215,8 +462,19 @@ pub mod pallet {
                 Error::<T>::IntakeClosed,
             );
 
-            // remove the application
-            Applications::<T>::remove(&intake_id, &student_id);
+            if is_applied {
+                // remove the application and give the freed seat to waitlist
+                Applications::<T>::remove(&intake_id, &student_id);
+                ApplicationScores::<T>::remove(&intake_id, &student_id);
+                IntakeCounters::<T>::mutate(&intake_id, |counter| {
+                    counter.applicants = counter.applicants.saturating_sub(1)
+                });
+                Self::promote_from_waitlist(&intake_id);
+            } else {
+                Waitlists::<T>::mutate(&intake_id, |waitlist| {
+                    waitlist.retain(|entry| entry.student != student_id)
+                });
+            }
 
             // emit the event
             Self::deposit_event(Event::ApplicationWithdrawn(intake_id, student_id));
@@ -242,6 +500,17 @@ pub mod pallet {
                 Error::<T>::IntakeNotClosed,
             );
 
+            // chain have to admit the applicants first
+            // and every offer have to be responded or lapsed
+            ensure!(
+                !PendingAdmissions::<T>::contains_key(&intake_id),
+                Error::<T>::AdmissionPending
+            );
+            ensure!(
+                Self::get_intake_counter(&intake_id).pending_offers == 0,
+                Error::<T>::OffersPending
+            );
+
             // update status to Finalised
             Intakes::<T>::mutate(&intake_id, |intake_info| {
                 if let Some(intake_info) = intake_info {
@@ -249,8 +518,9 @@ pub mod pallet {
                 };
             });
 
-            // remove all applications
-            Applications::<T>::remove_prefix(&intake_id, None);
+            // applications are removed by the hook in bounded steps
+            PendingCleanups::<T>::insert(&intake_id, IntakeCleanup::Applications(None));
+            Waitlists::<T>::remove(&intake_id);
 
             // emit the event
             Self::deposit_event(Event::<T>::IntakeFinalised(intake_id));
@@ -258,12 +528,193 @@ pub mod pallet {
             Ok(())
         }
 
+        /// give score to an application of intake with `ScoreBased` admission
+        /// intake have to be still ongoing as admission is done as soon as it closes
         #[pallet::weight(10_000)]
+        pub fn score_application(
+            origin: OriginFor<T>,
+            intake_id: IntakeIdOf<T>,
+            student_id: StudentIdOf<T>,
+            score: AdmissionScore,
+        ) -> DispatchResult {
+            let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let university_admin =
+                T::UniversityProvider::university_admin(&intake_id.university_id)
+                    .ok_or(Error::<T>::NonExistentUniversity)?;
+            ensure!(
+                signer == university_admin,
+                Error::<T>::InsufficientPermission
+            );
+
+            let intake_info = Self::get_intake(&intake_id).ok_or(Error::<T>::NonExistentIntake)?;
+            ensure!(
+                intake_info.admission_policy == AdmissionPolicy::ScoreBased,
+                Error::<T>::InvalidAdmissionPolicy
+            );
+            ensure!(
+                intake_info.status == IntakeStatus::IntakeOngoing,
+                Error::<T>::IntakeClosed
+            );
+            ensure!(
+                Applications::<T>::contains_key(&intake_id, &student_id),
+                Error::<T>::NonExistentApplication,
+            );
+
+            ApplicationScores::<T>::insert(&intake_id, &student_id, score);
+            Self::deposit_event(Event::ApplicationScored(intake_id, student_id, score));
+
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::accept_application())]
         pub fn accept_application(
             origin: OriginFor<T>,
             intake_id: IntakeIdOf<T>,
             student_id: StudentIdOf<T>,
         ) -> DispatchResult {
+            let intake_info = Self::ensure_can_accept(origin, &intake_id)?;
+            Self::do_accept_application(&intake_id, &intake_info, student_id)
+        }
+
+        /// accept multiple applications of same intake at once
+        /// failure of one application does not revert the others
+        /// and failed ones are reported in `ApplicationsBatchAccepted` event
+        #[pallet::weight(T::WeightInfo::accept_applications(student_ids.len() as u32))]
+        pub fn accept_applications(
+            origin: OriginFor<T>,
+            intake_id: IntakeIdOf<T>,
+            student_ids: BoundedVec<StudentIdOf<T>, T::MaxAcceptApplications>,
+        ) -> DispatchResult {
+            let intake_info = Self::ensure_can_accept(origin, &intake_id)?;
+
+            let mut accepted = 0_u32;
+            let mut failed = Vec::new();
+            for student_id in student_ids {
+                match Self::do_accept_application(&intake_id, &intake_info, student_id.clone()) {
+                    Ok(()) => accepted += 1,
+                    Err(error) => failed.push((student_id, error)),
+                }
+            }
+
+            Self::deposit_event(Event::ApplicationsBatchAccepted {
+                intake_id,
+                accepted,
+                failed,
+            });
+
+            Ok(())
+        }
+
+        /// accept the offer made by university on this intake
+        #[pallet::weight(10_000)]
+        pub fn confirm_enrollment(
+            origin: OriginFor<T>,
+            intake_id: IntakeIdOf<T>,
+        ) -> DispatchResult {
+            let student_id =
+                ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let offer = Self::ensure_pending_offer(&intake_id, &student_id)?;
+
+            // offer have to be responded before it lapses
+            ensure!(
+                Self::current_block_number() < offer.respond_by,
+                Error::<T>::OfferExpired
+            );
+
+            let intake_info = Self::get_intake(&intake_id).ok_or(Error::<T>::NonExistentIntake)?;
+            // applicants are tracked by account, enrollment is kept against the student profile
+            let student_profile =
+                T::StudentProvider::student_of(&student_id).ok_or(Error::<T>::NoStudent)?;
+            T::EnrollmentProvider::enroll(
+                &intake_id.university_id,
+                &student_profile,
+                EnrollmentSource::Intake(intake_id.clone()),
+                intake_info.programme,
+            )?;
+
+            OfferExpiryLookup::<T>::remove(&offer.respond_by, (&intake_id, &student_id));
+            AcceptedApplications::<T>::insert(
+                &intake_id,
+                &student_id,
+                AdmissionOffer {
+                    status: AdmissionOfferStatus::Confirmed,
+                    ..offer
+                },
+            );
+            IntakeCounters::<T>::mutate(&intake_id, |counter| {
+                counter.pending_offers = counter.pending_offers.saturating_sub(1)
+            });
+
+            Self::deposit_event(Event::EnrollmentConfirmed(intake_id, student_id));
+
+            Ok(())
+        }
+
+        /// decline the offer made by university on this intake
+        /// the seat is released for next applicant
+        #[pallet::weight(10_000)]
+        pub fn decline_offer(origin: OriginFor<T>, intake_id: IntakeIdOf<T>) -> DispatchResult {
+            let student_id =
+                ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let offer = Self::ensure_pending_offer(&intake_id, &student_id)?;
+
+            OfferExpiryLookup::<T>::remove(&offer.respond_by, (&intake_id, &student_id));
+            Self::release_offer(&intake_id, &student_id);
+
+            Self::deposit_event(Event::OfferDeclined(intake_id, student_id));
+
+            Ok(())
+        }
+    }
+
+    // extrinsic helpers
+    impl<T: Config> Pallet<T> {
+        /// page of intakes announced by `university_id`
+        /// starting after intake with index `start`
+        pub fn intakes_page(
+            university_id: &UniversityIdOf<T>,
+            start: Option<u32>,
+            limit: u32,
+        ) -> Page<IntakeIdOf<T>, IntakeInfo<BlockNumberOf<T>>> {
+            let Page { items, next } = double_map_page::<UniversityIntakes<T>, _, _, _>(
+                university_id.clone(),
+                start,
+                limit,
+            );
+            let to_intake_id = |intake_index| IntakeId {
+                university_id: university_id.clone(),
+                intake_index,
+            };
+
+            Page {
+                items: items
+                    .into_iter()
+                    .filter_map(|(intake_index, ())| {
+                        let intake_id = to_intake_id(intake_index);
+                        Self::get_intake(&intake_id).map(|intake| (intake_id, intake))
+                    })
+                    .collect(),
+                next: next.map(to_intake_id),
+            }
+        }
+
+        pub fn current_block_number() -> BlockNumberOf<T> {
+            <frame_system::Pallet<T>>::block_number()
+        }
+
+        pub fn is_waitlisted(intake_id: &IntakeIdOf<T>, student_id: &StudentIdOf<T>) -> bool {
+            Self::get_waitlist(intake_id)
+                .iter()
+                .any(|entry| &entry.student == student_id)
+        }
+
+        /// ensure `origin` is admin of intake's university and intake is closed
+        /// applications of intake with automatic admission are only accepted by the chain
+        /// return the intake info
+        fn ensure_can_accept(
+            origin: OriginFor<T>,
+            intake_id: &IntakeIdOf<T>,
+        ) -> Result<IntakeInfo<BlockNumberOf<T>>, DispatchError> {
             let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
             let university_admin =
                 T::UniversityProvider::university_admin(&intake_id.university_id)
@@ -276,50 +727,158 @@ pub mod pallet {
             );
 
             // get intake info
-            let intake_info = Self::get_intake(&intake_id).ok_or(Error::<T>::NonExistentIntake)?;
+            let intake_info = Self::get_intake(intake_id).ok_or(Error::<T>::NonExistentIntake)?;
             // ensre intake is closed
             ensure!(
                 intake_info.status == IntakeStatus::IntakeClosed,
                 Error::<T>::IntakeOngoing,
             );
+            ensure!(
+                !intake_info.admission_policy.is_automatic(),
+                Error::<T>::InvalidAdmissionPolicy
+            );
+
+            Ok(intake_info)
+        }
 
+        /// accept the application of `student_id` if intake still have a seat
+        /// nothing is written if this fails
+        fn do_accept_application(
+            intake_id: &IntakeIdOf<T>,
+            intake_info: &IntakeInfo<BlockNumberOf<T>>,
+            student_id: StudentIdOf<T>,
+        ) -> DispatchResult {
             // ensure application of this student exists
             ensure!(
-                Applications::<T>::contains_key(&intake_id, &student_id),
+                Applications::<T>::contains_key(intake_id, &student_id),
                 Error::<T>::NonExistentApplication,
             );
 
             // ensure this student has not been accepted before
             ensure!(
-                !AcceptedApplications::<T>::contains_key(&intake_id, &student_id),
+                !AcceptedApplications::<T>::contains_key(intake_id, &student_id),
                 Error::<T>::InvalidParamater,
             );
 
-            // put into accepted applications
-            AcceptedApplications::<T>::insert(&intake_id, &student_id, ());
+            // ensure intake still have seat to accept
+            IntakeCounters::<T>::try_mutate(intake_id, |counter| {
+                ensure!(
+                    counter.accepted < intake_info.max_accepted,
+                    Error::<T>::AcceptanceQuotaReached
+                );
+                counter.accepted += 1;
+                Ok::<(), Error<T>>(())
+            })?;
 
-            // emit event
-            Self::deposit_event(Event::ApplicationAccepted(intake_id, student_id));
+            // put into accepted applications
+            Self::make_offer(intake_id, student_id);
 
             Ok(())
         }
-    }
 
-    // extrinsic helpers
-    impl<T: Config> Pallet<T> {
-        pub fn current_block_number() -> BlockNumberOf<T> {
-            <frame_system::Pallet<T>>::block_number()
+        /// record the offer for accepted application and schedule it's lapse
+        /// caller is responsible to count it in `IntakeCounter::accepted`
+        fn make_offer(intake_id: &IntakeIdOf<T>, student_id: StudentIdOf<T>) {
+            let offered_on = Self::current_block_number();
+            let respond_by = offered_on.saturating_add(T::OfferResponsePeriod::get());
+
+            AcceptedApplications::<T>::insert(
+                intake_id,
+                &student_id,
+                AdmissionOffer {
+                    offered_on,
+                    respond_by,
+                    status: AdmissionOfferStatus::Pending,
+                },
+            );
+            OfferExpiryLookup::<T>::insert(&respond_by, (intake_id, &student_id), ());
+            IntakeCounters::<T>::mutate(intake_id, |counter| {
+                counter.pending_offers = counter.pending_offers.saturating_add(1)
+            });
+
+            Self::deposit_event(Event::ApplicationAccepted(intake_id.clone(), student_id));
+        }
+
+        /// return the offer made to `student_id` if it is waiting for response
+        fn ensure_pending_offer(
+            intake_id: &IntakeIdOf<T>,
+            student_id: &StudentIdOf<T>,
+        ) -> Result<AdmissionOfferOf<T>, DispatchError> {
+            let offer = Self::get_accepted_application(intake_id, student_id)
+                .ok_or(Error::<T>::NoPendingOffer)?;
+            ensure!(
+                offer.status == AdmissionOfferStatus::Pending,
+                Error::<T>::NoPendingOffer
+            );
+
+            Ok(offer)
+        }
+
+        /// remove the declined or lapsed offer along with it's application
+        /// and free the seat for next applicant
+        ///
+        /// oldest waitlisted application takes the freed application slot.
+        /// If chain admits the applicants, admission is run again so seat goes to
+        /// next ranked applicant according to the admission policy. Otherwise the seat
+        /// is left for university to offer
+        /// caller is responsible to remove the entry from `OfferExpiryLookup`
+        fn release_offer(intake_id: &IntakeIdOf<T>, student_id: &StudentIdOf<T>) {
+            AcceptedApplications::<T>::remove(intake_id, student_id);
+            Applications::<T>::remove(intake_id, student_id);
+            ApplicationScores::<T>::remove(intake_id, student_id);
+            IntakeCounters::<T>::mutate(intake_id, |counter| {
+                counter.accepted = counter.accepted.saturating_sub(1);
+                counter.pending_offers = counter.pending_offers.saturating_sub(1);
+                counter.applicants = counter.applicants.saturating_sub(1);
+            });
+
+            Self::promote_from_waitlist(intake_id);
+            let is_automatic = Self::get_intake(intake_id)
+                .map(|intake_info| intake_info.admission_policy.is_automatic())
+                .unwrap_or(false);
+            if is_automatic {
+                PendingAdmissions::<T>::insert(intake_id, ());
+            }
+        }
+
+        /// move the oldest waitlisted application ( if any ) to regular applications
+        fn promote_from_waitlist(intake_id: &IntakeIdOf<T>) {
+            let promoted = Waitlists::<T>::mutate(intake_id, |waitlist| {
+                if waitlist.is_empty() {
+                    None
+                } else {
+                    Some(waitlist.remove(0))
+                }
+            });
+
+            if let Some(WaitlistEntry {
+                student,
+                application,
+            }) = promoted
+            {
+                Applications::<T>::insert(intake_id, &student, application);
+                IntakeCounters::<T>::mutate(intake_id, |counter| {
+                    counter.applicants = counter.applicants.saturating_add(1)
+                });
+                Self::deposit_event(Event::PromotedFromWaitlist(intake_id.clone(), student));
+            }
         }
     }
 
-    // implement  a hook to delete intake info when the intake is closed
+    // implement a hook to open and close the intakes on their scheduled date
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberOf<T>> for Pallet<T> {
         fn on_initialize(current_block_number: BlockNumberOf<T>) -> Weight {
             let mut weight_consumed = Weight::zero();
 
-            // delete intake info when the intake closing date has passed
-            weight_consumed += Self::mark_close_intakes(current_block_number);
+            // open & close the intakes scheduled till this block
+            weight_consumed += Self::process_intake_schedule(current_block_number);
+
+            // admit the applicants of one closed intake
+            weight_consumed += Self::process_pending_admission();
+
+            // remove the applications of one finalised intake
+            weight_consumed += Self::process_pending_cleanup();
 
             // return weight consumed
             weight_consumed
@@ -328,21 +887,329 @@ pub mod pallet {
 
     // hooks helpers
     impl<T: Config> Pallet<T> {
-        /// delete intake info when the intake closing date has passed
-        fn mark_close_intakes(block_number: BlockNumberOf<T>) -> Weight {
-            let weight_consumed = Weight::zero();
-
-            let to_expire_intakes = IntakeClosingDateLookup::<T>::drain_prefix(&block_number);
-            for (intake_id, _) in to_expire_intakes {
-                Intakes::<T>::mutate(&intake_id, |intake_info| {
-                    if let Some(intake_info) = intake_info {
-                        intake_info.status = IntakeStatus::IntakeClosed;
+        /// open and close all intakes scheduled upto `current_block_number`
+        ///
+        /// at most `MaxIntakeTransitionsPerBlock` intakes are transitioned
+        /// and at most same number of blocks are scanned. Whatever is left
+        /// is carried over to next block through `SchedulerCursor`
+        fn process_intake_schedule(current_block_number: BlockNumberOf<T>) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let max_transitions = T::MaxIntakeTransitionsPerBlock::get();
+            let mut budget = max_transitions;
+            let mut scanned = 0_u32;
+
+            let cursor = SchedulerCursor::<T>::get();
+            let mut weight_consumed = db_weight.reads(1);
+
+            let mut block_number = cursor.unwrap_or(current_block_number);
+            while block_number <= current_block_number && budget > 0 && scanned < max_transitions {
+                // open before close so intake carried over for long
+                // still emits both the events in order
+                let (opening_drained, weight) = Self::open_intakes(block_number, &mut budget);
+                weight_consumed += weight;
+                let (closing_drained, weight) = Self::close_intakes(block_number, &mut budget);
+                weight_consumed += weight;
+                let (lapsing_drained, weight) = Self::lapse_offers(block_number, &mut budget);
+                weight_consumed += weight;
+
+                if !(opening_drained && closing_drained && lapsing_drained) {
+                    break;
+                }
+
+                block_number = block_number.saturating_add(One::one());
+                scanned += 1;
+            }
+
+            if block_number > current_block_number {
+                // nothing is left to carry over
+                if cursor.is_some() {
+                    SchedulerCursor::<T>::kill();
+                    weight_consumed += db_weight.writes(1);
+                }
+            } else if cursor != Some(block_number) {
+                SchedulerCursor::<T>::put(block_number);
+                weight_consumed += db_weight.writes(1);
+            }
+
+            weight_consumed
+        }
+
+        /// move the intakes scheduled to open on `block_number` to ongoing state
+        /// return whether all scheduled openings of this block were processed
+        fn open_intakes(block_number: BlockNumberOf<T>, budget: &mut u32) -> (bool, Weight) {
+            let (to_open, drained) =
+                Self::take_scheduled::<IntakeOpeningDateLookup<T>, _>(block_number, budget);
+            let weight_consumed = Self::transition_weight(to_open.len() as u64);
+
+            for intake_id in to_open {
+                if Self::transit_intake(
+                    &intake_id,
+                    &[IntakeStatus::IntakePending],
+                    IntakeStatus::IntakeOngoing,
+                ) {
+                    Self::deposit_event(Event::<T>::IntakeOpened(intake_id));
+                }
+            }
+
+            (drained, weight_consumed)
+        }
+
+        /// move the intakes scheduled to close on `block_number` to closed state
+        /// return whether all scheduled closings of this block were processed
+        fn close_intakes(block_number: BlockNumberOf<T>, budget: &mut u32) -> (bool, Weight) {
+            let (to_close, drained) =
+                Self::take_scheduled::<IntakeClosingDateLookup<T>, _>(block_number, budget);
+
+            let mut weight_consumed = Self::transition_weight(to_close.len() as u64);
+
+            for intake_id in to_close {
+                if Self::transit_intake(
+                    &intake_id,
+                    &[IntakeStatus::IntakePending, IntakeStatus::IntakeOngoing],
+                    IntakeStatus::IntakeClosed,
+                ) {
+                    // queue the intake for automatic admission if required
+                    if Self::get_intake(&intake_id)
+                        .map(|intake_info| intake_info.admission_policy.is_automatic())
+                        .unwrap_or(false)
+                    {
+                        PendingAdmissions::<T>::insert(&intake_id, ());
+                        weight_consumed += T::DbWeight::get().writes(1);
                     }
-                });
+                    Self::deposit_event(Event::<T>::IntakeClosed(intake_id));
+                }
+            }
+
+            (drained, weight_consumed)
+        }
+
+        /// release the offers that were not responded till `block_number`
+        /// return whether all scheduled lapses of this block were processed
+        fn lapse_offers(block_number: BlockNumberOf<T>, budget: &mut u32) -> (bool, Weight) {
+            let (to_lapse, drained) =
+                Self::take_scheduled::<OfferExpiryLookup<T>, _>(block_number, budget);
+            // per offer: 1 write to lookup, 4 writes to release offer, application,
+            // score & counter, 1 read of intake, 1 read & 3 writes to promote from waitlist
+            // and at most 1 write to queue admission
+            let count = to_lapse.len() as u64;
+            let weight_consumed = T::DbWeight::get().reads_writes(2 + 2 * count, 9 * count);
+
+            for (intake_id, student_id) in to_lapse {
+                Self::release_offer(&intake_id, &student_id);
+                Self::deposit_event(Event::<T>::OfferLapsed(intake_id, student_id));
+            }
+
+            (drained, weight_consumed)
+        }
+
+        /// admit the applicants of one intake from `PendingAdmissions`
+        /// one intake per block keeps the weight bounded by `MaxAutoAdmissionApplicants`
+        fn process_pending_admission() -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut weight_consumed = db_weight.reads(1);
+
+            let intake_id = match PendingAdmissions::<T>::iter_keys().next() {
+                Some(intake_id) => intake_id,
+                None => return weight_consumed,
+            };
+            PendingAdmissions::<T>::remove(&intake_id);
+            weight_consumed += db_weight.reads_writes(1, 1);
+
+            // seats released after intake is finalised are not offered again
+            if let Some(intake_info) = Self::get_intake(&intake_id) {
+                if intake_info.status == IntakeStatus::IntakeClosed {
+                    weight_consumed += Self::admit_applicants(&intake_id, &intake_info);
+                }
             }
 
             weight_consumed
         }
+
+        /// remove the applications and scores of one intake from `PendingCleanups`
+        ///
+        /// at most `MaxApplicationRemovalsPerBlock` entries are removed, the rest
+        /// is continued from the stored cursor in next block
+        fn process_pending_cleanup() -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut weight_consumed = db_weight.reads(1);
+
+            let (intake_id, mut cleanup) = match PendingCleanups::<T>::iter().next() {
+                Some(pending) => pending,
+                None => return weight_consumed,
+            };
+
+            let mut budget = T::MaxApplicationRemovalsPerBlock::get();
+            while budget > 0 {
+                let result = match &cleanup {
+                    IntakeCleanup::Applications(cursor) => Applications::<T>::clear_prefix(
+                        &intake_id,
+                        budget,
+                        cursor.as_ref().map(|cursor| cursor.as_slice()),
+                    ),
+                    IntakeCleanup::Scores(cursor) => ApplicationScores::<T>::clear_prefix(
+                        &intake_id,
+                        budget,
+                        cursor.as_ref().map(|cursor| cursor.as_slice()),
+                    ),
+                };
+                budget = budget.saturating_sub(result.loops.max(1));
+                weight_consumed +=
+                    db_weight.reads_writes(result.loops as u64, result.unique as u64);
+
+                cleanup = match (cleanup, result.maybe_cursor) {
+                    // cursor too long to be stored is dropped,
+                    // entries removed in earlier blocks are not iterated again anyway
+                    (IntakeCleanup::Applications(_), Some(cursor)) => {
+                        IntakeCleanup::Applications(CleanupCursor::try_from(cursor).ok())
+                    }
+                    (IntakeCleanup::Scores(_), Some(cursor)) => {
+                        IntakeCleanup::Scores(CleanupCursor::try_from(cursor).ok())
+                    }
+                    (IntakeCleanup::Applications(_), None) => IntakeCleanup::Scores(None),
+                    (IntakeCleanup::Scores(_), None) => {
+                        PendingCleanups::<T>::remove(&intake_id);
+                        Self::deposit_event(Event::<T>::IntakeCleanedUp(intake_id));
+                        return weight_consumed + db_weight.writes(1);
+                    }
+                };
+            }
+
+            PendingCleanups::<T>::insert(&intake_id, cleanup);
+            weight_consumed + db_weight.writes(1)
+        }
+
+        /// accept the best ranked applications of the intake according to it's admission policy
+        /// upto the seats left from `max_accepted`
+        fn admit_applicants(
+            intake_id: &IntakeIdOf<T>,
+            intake_info: &IntakeInfo<BlockNumberOf<T>>,
+        ) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let counter = Self::get_intake_counter(intake_id);
+            let seats = intake_info.max_accepted.saturating_sub(counter.accepted) as usize;
+
+            // applications that were already offered a seat are not ranked again
+            let candidates = Applications::<T>::iter_prefix(intake_id)
+                .filter(|(student_id, _)| {
+                    !AcceptedApplications::<T>::contains_key(intake_id, student_id)
+                })
+                .collect::<Vec<_>>();
+            let mut weight_consumed =
+                db_weight.reads(2_u64.saturating_add(2 * candidates.len() as u64));
+
+            let ranked: Vec<StudentIdOf<T>> = match intake_info.admission_policy {
+                AdmissionPolicy::Manual => Vec::new(),
+                AdmissionPolicy::FirstComeFirstServed => {
+                    let mut ranked = candidates
+                        .into_iter()
+                        .map(|(student_id, application)| (application.applied_on, student_id))
+                        .collect::<Vec<_>>();
+                    ranked.sort();
+                    ranked
+                        .into_iter()
+                        .map(|(_, student_id)| student_id)
+                        .collect()
+                }
+                AdmissionPolicy::ScoreBased => {
+                    // applications promoted from waitlist after the intake closed
+                    // cannot be scored anymore, so unscored ones are ranked last
+                    let mut ranked = candidates
+                        .into_iter()
+                        .map(|(student_id, application)| {
+                            let score = Self::get_application_score(intake_id, &student_id);
+                            (score, application.applied_on, student_id)
+                        })
+                        .collect::<Vec<_>>();
+                    weight_consumed += db_weight.reads(ranked.len() as u64);
+                    // highest score first, earlier application wins the tie
+                    ranked.sort_by(|a, b| {
+                        b.0.cmp(&a.0).then_with(|| (&a.1, &a.2).cmp(&(&b.1, &b.2)))
+                    });
+                    ranked
+                        .into_iter()
+                        .map(|(_, _, student_id)| student_id)
+                        .collect()
+                }
+                AdmissionPolicy::Lottery => {
+                    let (seed, _) = T::Randomness::random(&(b"intake/lottery", intake_id).encode());
+                    let mut ranked = candidates
+                        .into_iter()
+                        .map(|(student_id, _)| {
+                            (T::Hashing::hash_of(&(&seed, &student_id)), student_id)
+                        })
+                        .collect::<Vec<_>>();
+                    ranked.sort();
+                    ranked
+                        .into_iter()
+                        .map(|(_, student_id)| student_id)
+                        .collect()
+                }
+            };
+
+            let mut accepted = 0_u32;
+            for student_id in ranked.into_iter().take(seats) {
+                Self::make_offer(intake_id, student_id);
+                accepted += 1;
+            }
+            IntakeCounters::<T>::mutate(intake_id, |counter| {
+                counter.accepted = counter.accepted.saturating_add(accepted)
+            });
+            // per offer: 1 write to offers, 1 write to lookup, 1 read & write to counter
+            weight_consumed +=
+                db_weight.reads_writes(accepted as u64, 1_u64.saturating_add(3 * accepted as u64));
+
+            Self::deposit_event(Event::AutomaticAdmissionCompleted {
+                intake_id: intake_id.clone(),
+                accepted,
+            });
+
+            weight_consumed
+        }
+
+        /// remove and return at most `budget` entries scheduled in `Lookup` for `block_number`
+        /// also return whether nothing is left scheduled for that block
+        fn take_scheduled<Lookup, Key>(
+            block_number: BlockNumberOf<T>,
+            budget: &mut u32,
+        ) -> (Vec<Key>, bool)
+        where
+            Lookup: IterableStorageDoubleMap<BlockNumberOf<T>, Key, ()>,
+            Key: FullCodec,
+        {
+            let scheduled = Lookup::iter_key_prefix(block_number)
+                .take(*budget as usize)
+                .collect::<Vec<_>>();
+            for key in scheduled.iter() {
+                Lookup::remove(block_number, key);
+            }
+            *budget = budget.saturating_sub(scheduled.len() as u32);
+
+            let drained = Lookup::iter_key_prefix(block_number).next().is_none();
+            (scheduled, drained)
+        }
+
+        /// change status of intake to `to` if it's current status is one of `from`
+        /// return true if status was changed
+        fn transit_intake(
+            intake_id: &IntakeIdOf<T>,
+            from: &[IntakeStatus],
+            to: IntakeStatus,
+        ) -> bool {
+            Intakes::<T>::mutate(intake_id, |intake_info| match intake_info {
+                Some(intake_info) if from.contains(&intake_info.status) => {
+                    intake_info.status = to;
+                    true
+                }
+                _ => false,
+            })
+        }
+
+        /// weight of taking `count` intakes from a lookup and transiting them
+        /// - 2 reads to iterate the lookup prefix & check if it is drained
+        /// - per intake: 1 write to remove from lookup, 1 read and 1 write to update status
+        fn transition_weight(count: u64) -> Weight {
+            T::DbWeight::get().reads_writes(2 + count, 2 * count)
+        }
     }
 }
 
@@ -0,0 +1,282 @@
+use crate as pallet_intake;
+use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
+    parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64, Randomness},
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, Hash, IdentityLookup},
+};
+use sp_std::cell::RefCell;
+use traits::pallet_provider::{
+    EnrollmentProvider, ProfessorProvider, StudentProvider, UniversityProvider, ValidationProvider,
+};
+use types::{
+    primitives::{StdIpfsLink, UniqId},
+    professor::{NewOfferParam, OfferId, ProfessorId},
+    student::{ApplicationId, StudentId},
+    university::{EnrollmentSource, ProgrammeId, University, UniversityId},
+    validator::{ValidatorId, ValidatorTier},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+pub const UNIVERSITY_ADMIN: AccountId = 1;
+pub const OTHER_ACCOUNT: AccountId = 2;
+
+/// only university known to `MockUniversityProvider`
+pub const UNIVERSITY: UniqId = UniqId::new([1; 32]);
+
+pub const OFFER_RESPONSE_PERIOD: u64 = 10;
+pub const MAX_WAITLIST_LENGTH: u32 = 2;
+pub const MAX_TRANSITIONS_PER_BLOCK: u32 = 2;
+pub const MAX_REMOVALS_PER_BLOCK: u32 = 2;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Intake: pallet_intake,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+    pub TrustedValidators: Vec<ValidatorId> = Vec::new();
+}
+
+impl pallet_intake::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ProfessorProvider = MockProfessorProvider;
+    type UniversityProvider = MockUniversityProvider;
+    type StudentProvider = MockStudentProvider;
+    type EnrollmentProvider = MockEnrollmentProvider;
+    type ValidationProvider = MockValidationProvider;
+    type TrustedValidators = TrustedValidators;
+    type MaxIntakeTransitionsPerBlock = ConstU32<MAX_TRANSITIONS_PER_BLOCK>;
+    type MaxWaitlistLength = ConstU32<MAX_WAITLIST_LENGTH>;
+    type MaxAcceptApplications = ConstU32<10>;
+    type MaxAutoAdmissionApplicants = ConstU32<10>;
+    type MaxApplicationRemovalsPerBlock = ConstU32<MAX_REMOVALS_PER_BLOCK>;
+    type OfferResponsePeriod = ConstU64<OFFER_RESPONSE_PERIOD>;
+    type Randomness = MockRandomness;
+    type WeightInfo = ();
+}
+
+pub fn ipfs_link() -> StdIpfsLink {
+    StdIpfsLink::new(Default::default())
+}
+
+pub struct MockUniversityProvider;
+impl UniversityProvider for MockUniversityProvider {
+    type UniversityId = UniversityId;
+    type UniversityInfo = University<AccountId>;
+    type FrameConfig = Test;
+
+    fn university_info(university_id: &Self::UniversityId) -> Option<Self::UniversityInfo> {
+        (university_id == &UNIVERSITY).then(|| University {
+            admin: UNIVERSITY_ADMIN,
+            permanent_info: ipfs_link(),
+        })
+    }
+
+    fn university_admin(university_id: &Self::UniversityId) -> Option<AccountId> {
+        Self::university_info(university_id).map(|info| info.admin)
+    }
+
+    fn note_affiliation(_: &Self::UniversityId, _: &ProfessorId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn note_disaffiliation(_: &Self::UniversityId, _: &ProfessorId) {}
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register_university_for_benchmark(_: AccountId) -> Self::UniversityId {
+        UNIVERSITY
+    }
+}
+
+pub struct MockProfessorProvider;
+impl ProfessorProvider for MockProfessorProvider {
+    type ProfessorId = ProfessorId;
+    type ProfessorInfo = ();
+    type AffiliationInfo = ();
+    type FrameConfig = Test;
+
+    fn professor_info(_: &Self::ProfessorId) -> Option<()> {
+        None
+    }
+
+    fn affiliation_info(_: &Self::ProfessorId, _: &UniversityId) -> Option<()> {
+        None
+    }
+
+    fn is_affiliated(_: &Self::ProfessorId, _: &UniversityId) -> bool {
+        false
+    }
+
+    fn make_offer(_: &AccountId, _: NewOfferParam<u64>) -> Result<OfferId, DispatchError> {
+        Err(DispatchError::Other("no professor"))
+    }
+}
+
+/// student profile registered by `account` in `MockStudentProvider`
+pub fn student_id_of(account: AccountId) -> StudentId {
+    UniqId::new([account as u8; 32])
+}
+
+/// every account is registered as a student
+pub struct MockStudentProvider;
+impl StudentProvider for MockStudentProvider {
+    type StudentId = StudentId;
+    type StudentInfo = ();
+    type ApplicationId = ApplicationId;
+    type ApplicationInfo = ();
+    type FrameConfig = Test;
+
+    fn student_info(_: &StudentId) -> Option<()> {
+        Some(())
+    }
+
+    fn student_of(account: &AccountId) -> Option<StudentId> {
+        Some(student_id_of(*account))
+    }
+
+    fn note_enrollment(_: &StudentId, _: &UniversityId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn note_graduation(_: &StudentId, _: &UniversityId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn note_withdrawal(_: &StudentId, _: &UniversityId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn application_info(_: &ApplicationId) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("no application"))
+    }
+
+    fn close_application(_: &ApplicationId) -> DispatchResult {
+        Err(DispatchError::Other("no application"))
+    }
+}
+
+thread_local! {
+    static ENROLLMENTS: RefCell<Vec<(UniversityId, StudentId)>> = RefCell::new(Vec::new());
+}
+
+/// enrollments are kept in memory of the test thread
+pub struct MockEnrollmentProvider;
+impl EnrollmentProvider for MockEnrollmentProvider {
+    type UniversityId = UniversityId;
+    type StudentId = StudentId;
+    type EnrollmentInfo = ();
+
+    fn enrollment_info(university_id: &UniversityId, student_id: &StudentId) -> Option<()> {
+        ENROLLMENTS.with(|enrollments| {
+            enrollments
+                .borrow()
+                .contains(&(*university_id, *student_id))
+                .then_some(())
+        })
+    }
+
+    fn enroll(
+        university_id: &UniversityId,
+        student_id: &StudentId,
+        _: EnrollmentSource,
+        _: Option<ProgrammeId>,
+    ) -> DispatchResult {
+        if Self::is_enrolled(university_id, student_id) {
+            return Err(DispatchError::Other("already enrolled"));
+        }
+        ENROLLMENTS
+            .with(|enrollments| enrollments.borrow_mut().push((*university_id, *student_id)));
+        Ok(())
+    }
+}
+
+pub struct MockValidationProvider;
+impl ValidationProvider for MockValidationProvider {
+    type ValidatorId = ValidatorId;
+    type UniversityId = UniversityId;
+    type ProfessorId = ProfessorId;
+    type ValidatedUniversityInfo = ();
+    type ValidatedProfessorInfo = ();
+
+    fn validated_university_info(_: &ValidatorId, _: &UniversityId) -> Option<()> {
+        None
+    }
+
+    fn validated_professor_info(_: &ValidatorId, _: &ProfessorId) -> Option<()> {
+        None
+    }
+
+    fn validator_tier(_: &ValidatorId) -> Option<ValidatorTier> {
+        None
+    }
+
+    fn university_validators(_: &UniversityId) -> Vec<ValidatorId> {
+        Vec::new()
+    }
+
+    fn professor_validators(_: &ProfessorId) -> Vec<ValidatorId> {
+        Vec::new()
+    }
+}
+
+/// deterministic randomness derived from the subject only
+pub struct MockRandomness;
+impl Randomness<H256, u64> for MockRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        (BlakeTwo256::hash(subject), 0)
+    }
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    ENROLLMENTS.with(|enrollments| enrollments.borrow_mut().clear());
+    let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap()
+        .into();
+    // events are not deposited on genesis block
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
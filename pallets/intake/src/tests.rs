@@ -0,0 +1,479 @@
+use crate::{
+    mock::*, AcceptedApplications, Applications, Error, Event, IntakeCounters, PendingCleanups,
+    SchedulerCursor, Waitlists,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use traits::pallet_provider::EnrollmentProvider;
+use types::intake::{
+    AdmissionOfferStatus, AdmissionPolicy, IntakeApplication, IntakeCounter, IntakeId,
+    IntakeStatus, NewIntakeParam,
+};
+
+const STUDENT: AccountId = 10;
+const WAITLISTED: AccountId = 11;
+const LATE: AccountId = 12;
+
+/// block on which intakes announced by `announce` close
+const CLOSES: u64 = 3;
+
+fn intake_id(intake_index: u32) -> IntakeId<types::university::UniversityId> {
+    IntakeId {
+        university_id: UNIVERSITY,
+        intake_index,
+    }
+}
+
+fn run_to_block(block_number: u64) {
+    while System::block_number() < block_number {
+        System::set_block_number(System::block_number() + 1);
+        Intake::on_initialize(System::block_number());
+    }
+}
+
+fn announce_intake(
+    intake_index: u32,
+    application_opens: u64,
+    max_applicants: u32,
+    max_accepted: u32,
+    admission_policy: AdmissionPolicy,
+) {
+    assert_ok!(Intake::announce_intake_application(
+        RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+        intake_id(intake_index),
+        UNIVERSITY,
+        NewIntakeParam {
+            application_opens,
+            application_closes: CLOSES,
+            max_applicants,
+            max_accepted,
+            admission_policy,
+            programme: None,
+        },
+    ));
+}
+
+fn apply(intake_index: u32, student: AccountId) -> frame_support::dispatch::DispatchResult {
+    Intake::apply_for_intake(
+        RuntimeOrigin::signed(student),
+        intake_id(intake_index),
+        IntakeApplication {
+            applied_on: 0,
+            info: ipfs_link(),
+        },
+    )
+}
+
+fn status(intake_index: u32) -> IntakeStatus {
+    Intake::get_intake(intake_id(intake_index))
+        .expect("intake is announced")
+        .status
+}
+
+/// closed manual intake with one seat, `STUDENT` applied and `WAITLISTED` in waitlist
+fn closed_intake_with_waitlist() {
+    announce_intake(0, 1, 1, 1, AdmissionPolicy::Manual);
+    assert_ok!(apply(0, STUDENT));
+    assert_ok!(apply(0, WAITLISTED));
+    run_to_block(CLOSES);
+}
+
+#[test]
+fn intake_opens_and_closes_on_schedule() {
+    new_test_ext().execute_with(|| {
+        announce_intake(0, 2, 1, 1, AdmissionPolicy::Manual);
+        assert_eq!(status(0), IntakeStatus::IntakePending);
+        assert_noop!(apply(0, STUDENT), Error::<Test>::IntakeClosed);
+
+        run_to_block(2);
+        assert_eq!(status(0), IntakeStatus::IntakeOngoing);
+        System::assert_last_event(Event::IntakeOpened(intake_id(0)).into());
+        assert_ok!(apply(0, STUDENT));
+
+        run_to_block(CLOSES);
+        assert_eq!(status(0), IntakeStatus::IntakeClosed);
+        System::assert_last_event(Event::IntakeClosed(intake_id(0)).into());
+        assert_noop!(apply(0, LATE), Error::<Test>::IntakeClosed);
+    });
+}
+
+#[test]
+fn transitions_over_the_limit_are_carried_over() {
+    new_test_ext().execute_with(|| {
+        // three intakes closing on same block with a limit of two per block
+        for intake_index in 0..3 {
+            announce_intake(intake_index, 1, 1, 1, AdmissionPolicy::Manual);
+        }
+
+        run_to_block(CLOSES);
+        let closed = (0..3)
+            .filter(|intake_index| status(*intake_index) == IntakeStatus::IntakeClosed)
+            .count();
+        assert_eq!(closed, MAX_TRANSITIONS_PER_BLOCK as usize);
+        assert_eq!(SchedulerCursor::<Test>::get(), Some(CLOSES));
+
+        run_to_block(CLOSES + 1);
+        assert!((0..3).all(|intake_index| status(intake_index) == IntakeStatus::IntakeClosed));
+        assert_eq!(SchedulerCursor::<Test>::get(), None);
+    });
+}
+
+#[test]
+fn applications_over_the_cap_are_waitlisted() {
+    new_test_ext().execute_with(|| {
+        announce_intake(0, 1, 1, 1, AdmissionPolicy::Manual);
+        assert_ok!(apply(0, STUDENT));
+        assert_noop!(apply(0, STUDENT), Error::<Test>::InvalidParamater);
+
+        assert_ok!(apply(0, WAITLISTED));
+        System::assert_last_event(Event::AddedToWaitlist(intake_id(0), WAITLISTED).into());
+        assert!(Intake::is_waitlisted(&intake_id(0), &WAITLISTED));
+
+        assert_ok!(apply(0, LATE));
+        assert_eq!(
+            Waitlists::<Test>::get(intake_id(0)).len(),
+            MAX_WAITLIST_LENGTH as usize
+        );
+        assert_noop!(apply(0, OTHER_ACCOUNT), Error::<Test>::IntakeFull);
+    });
+}
+
+#[test]
+fn withdrawal_promotes_from_waitlist() {
+    new_test_ext().execute_with(|| {
+        announce_intake(0, 1, 1, 1, AdmissionPolicy::Manual);
+        assert_ok!(apply(0, STUDENT));
+        assert_ok!(apply(0, WAITLISTED));
+
+        assert_ok!(Intake::withdraw_application(
+            RuntimeOrigin::signed(STUDENT),
+            intake_id(0)
+        ));
+
+        assert!(Applications::<Test>::contains_key(intake_id(0), WAITLISTED));
+        assert!(!Intake::is_waitlisted(&intake_id(0), &WAITLISTED));
+        assert_eq!(IntakeCounters::<Test>::get(intake_id(0)).applicants, 1);
+        System::assert_has_event(Event::PromotedFromWaitlist(intake_id(0), WAITLISTED).into());
+    });
+}
+
+#[test]
+fn acceptance_is_limited_by_quota() {
+    new_test_ext().execute_with(|| {
+        announce_intake(0, 1, 2, 1, AdmissionPolicy::Manual);
+        assert_ok!(apply(0, STUDENT));
+        assert_ok!(apply(0, LATE));
+
+        assert_noop!(
+            Intake::accept_application(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                intake_id(0),
+                STUDENT
+            ),
+            Error::<Test>::IntakeOngoing
+        );
+        run_to_block(CLOSES);
+
+        assert_noop!(
+            Intake::accept_application(RuntimeOrigin::signed(OTHER_ACCOUNT), intake_id(0), STUDENT),
+            Error::<Test>::InsufficientPermission
+        );
+        assert_ok!(Intake::accept_application(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            intake_id(0),
+            STUDENT
+        ));
+        assert_noop!(
+            Intake::accept_application(RuntimeOrigin::signed(UNIVERSITY_ADMIN), intake_id(0), LATE),
+            Error::<Test>::AcceptanceQuotaReached
+        );
+        assert_eq!(
+            IntakeCounters::<Test>::get(intake_id(0)),
+            IntakeCounter {
+                applicants: 2,
+                accepted: 1,
+                pending_offers: 1,
+            }
+        );
+    });
+}
+
+#[test]
+fn automatic_admission_cannot_be_bypassed() {
+    new_test_ext().execute_with(|| {
+        for (intake_index, admission_policy) in [
+            AdmissionPolicy::FirstComeFirstServed,
+            AdmissionPolicy::ScoreBased,
+            AdmissionPolicy::Lottery,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let intake_index = intake_index as u32;
+            announce_intake(intake_index, 1, 2, 1, admission_policy);
+            assert_ok!(apply(intake_index, STUDENT));
+        }
+        run_to_block(CLOSES);
+
+        for intake_index in 0..3 {
+            assert_noop!(
+                Intake::accept_application(
+                    RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                    intake_id(intake_index),
+                    STUDENT
+                ),
+                Error::<Test>::InvalidAdmissionPolicy
+            );
+            assert_noop!(
+                Intake::accept_applications(
+                    RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                    intake_id(intake_index),
+                    vec![STUDENT].try_into().unwrap()
+                ),
+                Error::<Test>::InvalidAdmissionPolicy
+            );
+        }
+    });
+}
+
+#[test]
+fn confirm_enrollment_works() {
+    new_test_ext().execute_with(|| {
+        closed_intake_with_waitlist();
+        assert_ok!(Intake::accept_application(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            intake_id(0),
+            STUDENT
+        ));
+
+        assert_ok!(Intake::confirm_enrollment(
+            RuntimeOrigin::signed(STUDENT),
+            intake_id(0)
+        ));
+
+        assert!(MockEnrollmentProvider::is_enrolled(
+            &UNIVERSITY,
+            &student_id_of(STUDENT)
+        ));
+        assert_eq!(
+            Intake::get_accepted_application(intake_id(0), STUDENT).map(|offer| offer.status),
+            Some(AdmissionOfferStatus::Confirmed)
+        );
+        assert_eq!(IntakeCounters::<Test>::get(intake_id(0)).pending_offers, 0);
+        // seat is taken so waitlist stays as is
+        assert!(Intake::is_waitlisted(&intake_id(0), &WAITLISTED));
+    });
+}
+
+#[test]
+fn declined_seat_is_left_for_university_to_offer() {
+    new_test_ext().execute_with(|| {
+        closed_intake_with_waitlist();
+        assert_ok!(Intake::accept_application(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            intake_id(0),
+            STUDENT
+        ));
+
+        assert_ok!(Intake::decline_offer(
+            RuntimeOrigin::signed(STUDENT),
+            intake_id(0)
+        ));
+
+        assert!(!Applications::<Test>::contains_key(intake_id(0), STUDENT));
+        assert!(!AcceptedApplications::<Test>::contains_key(
+            intake_id(0),
+            STUDENT
+        ));
+        // waitlisted applicant takes the application slot but is not offered the seat
+        assert!(Applications::<Test>::contains_key(intake_id(0), WAITLISTED));
+        assert!(!AcceptedApplications::<Test>::contains_key(
+            intake_id(0),
+            WAITLISTED
+        ));
+        assert_eq!(
+            IntakeCounters::<Test>::get(intake_id(0)),
+            IntakeCounter {
+                applicants: 1,
+                accepted: 0,
+                pending_offers: 0,
+            }
+        );
+        System::assert_has_event(Event::OfferDeclined(intake_id(0), STUDENT).into());
+        System::assert_has_event(Event::PromotedFromWaitlist(intake_id(0), WAITLISTED).into());
+
+        // university reviews the promoted application before offering the seat
+        assert_ok!(Intake::accept_application(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            intake_id(0),
+            WAITLISTED
+        ));
+        assert_eq!(
+            Intake::get_accepted_application(intake_id(0), WAITLISTED).map(|offer| offer.status),
+            Some(AdmissionOfferStatus::Pending)
+        );
+    });
+}
+
+#[test]
+fn lapsed_offer_releases_the_seat() {
+    new_test_ext().execute_with(|| {
+        closed_intake_with_waitlist();
+        assert_ok!(Intake::accept_application(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            intake_id(0),
+            STUDENT
+        ));
+        let respond_by = CLOSES + OFFER_RESPONSE_PERIOD;
+
+        run_to_block(respond_by - 1);
+        assert!(AcceptedApplications::<Test>::contains_key(
+            intake_id(0),
+            STUDENT
+        ));
+
+        run_to_block(respond_by);
+        assert!(!AcceptedApplications::<Test>::contains_key(
+            intake_id(0),
+            STUDENT
+        ));
+        assert_noop!(
+            Intake::confirm_enrollment(RuntimeOrigin::signed(STUDENT), intake_id(0)),
+            Error::<Test>::NoPendingOffer
+        );
+        System::assert_has_event(Event::OfferLapsed(intake_id(0), STUDENT).into());
+
+        assert!(Applications::<Test>::contains_key(intake_id(0), WAITLISTED));
+        assert!(!AcceptedApplications::<Test>::contains_key(
+            intake_id(0),
+            WAITLISTED
+        ));
+        assert_eq!(IntakeCounters::<Test>::get(intake_id(0)).accepted, 0);
+    });
+}
+
+#[test]
+fn automatic_admission_offers_released_seat_again() {
+    new_test_ext().execute_with(|| {
+        announce_intake(0, 1, 3, 1, AdmissionPolicy::FirstComeFirstServed);
+        assert_ok!(apply(0, STUDENT));
+        run_to_block(2);
+        assert_ok!(apply(0, LATE));
+
+        // intake closes and admits earliest applicant in same block
+        run_to_block(CLOSES);
+        assert!(AcceptedApplications::<Test>::contains_key(
+            intake_id(0),
+            STUDENT
+        ));
+        assert!(!AcceptedApplications::<Test>::contains_key(
+            intake_id(0),
+            LATE
+        ));
+
+        assert_ok!(Intake::decline_offer(
+            RuntimeOrigin::signed(STUDENT),
+            intake_id(0)
+        ));
+        run_to_block(CLOSES + 1);
+        assert!(AcceptedApplications::<Test>::contains_key(
+            intake_id(0),
+            LATE
+        ));
+        assert_eq!(IntakeCounters::<Test>::get(intake_id(0)).accepted, 1);
+    });
+}
+
+#[test]
+fn unscored_applicant_is_admitted_after_scored_ones() {
+    new_test_ext().execute_with(|| {
+        announce_intake(0, 1, 1, 1, AdmissionPolicy::ScoreBased);
+        assert_ok!(apply(0, STUDENT));
+        assert_ok!(apply(0, WAITLISTED));
+        assert_ok!(Intake::score_application(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            intake_id(0),
+            STUDENT,
+            50
+        ));
+        run_to_block(CLOSES);
+        assert!(AcceptedApplications::<Test>::contains_key(
+            intake_id(0),
+            STUDENT
+        ));
+
+        // waitlisted applicant is promoted after close and can no longer be scored
+        assert_ok!(Intake::decline_offer(
+            RuntimeOrigin::signed(STUDENT),
+            intake_id(0)
+        ));
+        assert!(Applications::<Test>::contains_key(intake_id(0), WAITLISTED));
+        assert_noop!(
+            Intake::score_application(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                intake_id(0),
+                WAITLISTED,
+                10
+            ),
+            Error::<Test>::IntakeClosed
+        );
+
+        run_to_block(CLOSES + 1);
+        assert_eq!(
+            Intake::get_accepted_application(intake_id(0), WAITLISTED).map(|offer| offer.status),
+            Some(AdmissionOfferStatus::Pending)
+        );
+    });
+}
+
+#[test]
+fn finalised_intake_is_cleaned_up_in_steps() {
+    let mut ext = new_test_ext();
+    ext.execute_with(|| {
+        announce_intake(0, 1, 3, 1, AdmissionPolicy::Manual);
+        for student in [STUDENT, WAITLISTED, LATE] {
+            assert_ok!(apply(0, student));
+        }
+        run_to_block(CLOSES);
+
+        assert_ok!(Intake::finalise_intake(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            intake_id(0)
+        ));
+        assert_eq!(status(0), IntakeStatus::IntakeFinalised);
+        assert!(PendingCleanups::<Test>::contains_key(intake_id(0)));
+    });
+    // removal limit only applies to entries committed to the backend
+    ext.commit_all().unwrap();
+
+    ext.execute_with(|| {
+        // three applications with a limit of two removals per block
+        run_to_block(CLOSES + 1);
+        assert_eq!(
+            Applications::<Test>::iter_prefix(intake_id(0)).count(),
+            3 - MAX_REMOVALS_PER_BLOCK as usize
+        );
+        assert!(PendingCleanups::<Test>::contains_key(intake_id(0)));
+
+        run_to_block(CLOSES + 2);
+        assert_eq!(Applications::<Test>::iter_prefix(intake_id(0)).count(), 0);
+        assert!(!PendingCleanups::<Test>::contains_key(intake_id(0)));
+        System::assert_last_event(Event::IntakeCleanedUp(intake_id(0)).into());
+    });
+}
+
+#[test]
+fn finalise_requires_responded_offers() {
+    new_test_ext().execute_with(|| {
+        closed_intake_with_waitlist();
+        assert_ok!(Intake::accept_application(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            intake_id(0),
+            STUDENT
+        ));
+
+        assert_noop!(
+            Intake::finalise_intake(RuntimeOrigin::signed(UNIVERSITY_ADMIN), intake_id(0)),
+            Error::<Test>::OffersPending
+        );
+    });
+}
@@ -0,0 +1,85 @@
+//! Weights for pallet_intake
+//!
+//! THESE ARE HAND WRITTEN PLACEHOLDERS, NOT BENCHMARK RESULTS.
+//! Execution times are estimates and only the storage access is counted from the code.
+//! Replace this file with the output of `benchmark pallet` on reference hardware:
+//!
+//! ./target/release/edu-chain benchmark pallet
+//! --chain=dev
+//! --pallet=pallet_intake
+//! --extrinsic=*
+//! --steps=50
+//! --repeat=20
+//! --output=./pallets/intake/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_intake.
+pub trait WeightInfo {
+	fn accept_application() -> Weight;
+	fn accept_applications(n: u32, ) -> Weight;
+}
+
+/// Weights for pallet_intake using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: University Universities (r:1 w:0)
+	// Storage: Intake Intakes (r:1 w:0)
+	// Storage: Intake Applications (r:1 w:0)
+	// Storage: Intake AcceptedApplications (r:1 w:1)
+	// Storage: Intake IntakeCounters (r:1 w:1)
+	// Storage: Intake OfferExpiryLookup (r:0 w:1)
+	fn accept_application() -> Weight {
+		Weight::from_ref_time(32_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: University Universities (r:1 w:0)
+	// Storage: Intake Intakes (r:1 w:0)
+	// Storage: Intake Applications (r:1 w:0)
+	// Storage: Intake AcceptedApplications (r:1 w:1)
+	// Storage: Intake IntakeCounters (r:1 w:1)
+	// Storage: Intake OfferExpiryLookup (r:0 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	fn accept_applications(n: u32, ) -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(14_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: University Universities (r:1 w:0)
+	// Storage: Intake Intakes (r:1 w:0)
+	// Storage: Intake Applications (r:1 w:0)
+	// Storage: Intake AcceptedApplications (r:1 w:1)
+	// Storage: Intake IntakeCounters (r:1 w:1)
+	// Storage: Intake OfferExpiryLookup (r:0 w:1)
+	fn accept_application() -> Weight {
+		Weight::from_ref_time(32_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: University Universities (r:1 w:0)
+	// Storage: Intake Intakes (r:1 w:0)
+	// Storage: Intake Applications (r:1 w:0)
+	// Storage: Intake AcceptedApplications (r:1 w:1)
+	// Storage: Intake IntakeCounters (r:1 w:1)
+	// Storage: Intake OfferExpiryLookup (r:0 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	fn accept_applications(n: u32, ) -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(14_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+	}
+}
@@ -6,7 +6,9 @@ pub use pallet::*;
 pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
+    use pallet_provider_traits::{ProfessorProvider, UniversityProvider};
     use traits::pallet_provider as pallet_provider_traits;
+    pub(super) use types::lecture::*;
     use types::{professor::ProfessorId, university::UniversityId};
 
     #[pallet::pallet]
@@ -17,21 +19,33 @@ pub mod pallet {
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-        type UniversityProvider: pallet_provider_traits::UniversityProvider;
-        type ProfessorProvider: pallet_provider_traits::ProfessorProvider;
+        type UniversityProvider: UniversityProvider<UniversityId = UniversityId, FrameConfig = Self>;
+        type ProfessorProvider: ProfessorProvider<ProfessorId = ProfessorId>;
         type StudentProvider: pallet_provider_traits::StudentProvider;
     }
 
     #[pallet::storage]
-    #[pallet::getter(fn something)]
-    pub type Something<T> = StorageValue<_, u32>;
+    #[pallet::getter(fn get_lecture)]
+    pub type Lectures<T> = StorageMap<_, Twox64Concat, LectureId, Lecture>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {}
+    pub enum Event<T: Config> {
+        /// New lecture have been created
+        LectureCreated(LectureId),
+    }
 
     #[pallet::error]
-    pub enum Error<T> {}
+    pub enum Error<T> {
+        /// Origin cannot perform this action
+        InsufficientPermission,
+        /// No university with given id exists
+        NoUniversity,
+        /// Professor does not exists
+        NoProfessor,
+        /// Lecture with this id already exists
+        LectureExists,
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -41,13 +55,35 @@ pub mod pallet {
             university: UniversityId,
             faculty: (),
             professor: ProfessorId,
-            lecture_id: (),
-            lecture_details: (),
+            lecture_id: LectureId,
+            lecture_details: NewLectureParam,
         ) -> DispatchResult {
             // start a new lecture of this faculty of this university
             // lecture details (might) be the ipfs link to permanent lecture details
             // or onchain details like time start, time end, number of credit carries
             // etc..
+            let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let university_admin = T::UniversityProvider::university_admin(&university)
+                .ok_or(Error::<T>::NoUniversity)?;
+            ensure!(
+                signer == university_admin,
+                Error::<T>::InsufficientPermission
+            );
+            T::ProfessorProvider::professor_info(&professor).ok_or(Error::<T>::NoProfessor)?;
+            ensure!(
+                !Lectures::<T>::contains_key(&lecture_id),
+                Error::<T>::LectureExists
+            );
+
+            let NewLectureParam { details } = lecture_details;
+            let lecture = Lecture {
+                university,
+                professor,
+                details,
+            };
+
+            Lectures::<T>::insert(&lecture_id, lecture);
+            Self::deposit_event(Event::<T>::LectureCreated(lecture_id));
 
             Ok(())
         }
@@ -68,7 +104,10 @@ pub mod pallet {
 }
 
 impl<T: Config> traits::pallet_provider::LectureProvider for Pallet<T> {
-    fn get_lecture_info() {
-        todo!()
+    type LectureId = crate::LectureId;
+    type LectureInfo = crate::Lecture;
+
+    fn lecture_info(lecture_id: &Self::LectureId) -> Option<Self::LectureInfo> {
+        crate::Pallet::<T>::get_lecture(lecture_id)
     }
 }
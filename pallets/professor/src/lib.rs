@@ -2,21 +2,44 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::{pallet_prelude::*, Twox64Concat};
+    use frame_support::{
+        pallet_prelude::*,
+        sp_runtime::{
+            traits::{One, Saturating, Zero},
+            SaturatedConversion,
+        },
+        sp_std::vec::Vec,
+        traits::{Currency, ReservableCurrency},
+        Twox64Concat,
+    };
     use frame_system::pallet_prelude::*;
     use pallet_provider_traits::*;
     pub(super) use traits::pallet_provider as pallet_provider_traits;
+    use traits::{
+        id_generator::{bump_nonce, derive_uniq_id, IdGenerator},
+        pagination::double_map_page,
+    };
     pub(super) use types::{
-        primitives::{AccountIdOf, StdIpfsLink as IpfsLink},
+        primitives::{AccountIdOf, IdEntityKind, Page, StdIpfsLink as IpfsLink},
         professor::*,
-        university::UniversityId,
+        university::{FacultyId, UniversityId},
     };
 
     pub(super) type NewProfessorParamFor = NewProfessorParam;
     pub(super) type ProfessorInfoFor<T> = ProfessorInfo<AccountIdOf<T>>;
     pub(super) type UniversityInfoFor<T> = types::university::University<types::AccountIdOf<T>>;
+    pub(super) type AffiliationFor<T> = Affiliation<BlockNumberFor<T>>;
+    pub(super) type OfferInfoFor<T> = OfferInfo<BlockNumberFor<T>>;
+    pub(super) type NewOfferParamFor<T> = NewOfferParam<BlockNumberFor<T>>;
+    pub(super) type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
@@ -29,8 +52,37 @@ pub mod pallet {
         type UniversityProvider: UniversityProvider<
             UniversityId = UniversityId,
             UniversityInfo = UniversityInfoFor<Self>,
+            FrameConfig = Self,
         >;
         type StudentProvider: StudentProvider;
+
+        /// Currency in which offer deposit is reserved
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Deposit reserved from university for every offer regardless of it's validity
+        #[pallet::constant]
+        type OfferDepositBase: Get<BalanceOf<Self>>;
+
+        /// Additional deposit reserved for every block offer stays valid
+        #[pallet::constant]
+        type OfferDepositPerBlock: Get<BalanceOf<Self>>;
+
+        /// Maximum number of blocks an offer can stay valid
+        #[pallet::constant]
+        type MaxOfferValidity: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of offers that can be expired in a single block
+        /// remaining offers are carried over to next block
+        #[pallet::constant]
+        type MaxOfferExpiriesPerBlock: Get<u32>;
+
+        /// Maximum number of open offers a professor can have at once
+        #[pallet::constant]
+        type MaxOpenOffersPerProfessor: Get<u32>;
+
+        /// Maximum number of open offers an university can have at once
+        #[pallet::constant]
+        type MaxOpenOffersPerUniversity: Get<u32>;
     }
 
     #[pallet::storage]
@@ -57,9 +109,57 @@ pub mod pallet {
     #[pallet::storage]
     pub type UnusableProfessorId<T> = StorageMap<_, Identity, ProfessorId, ()>;
 
+    /// Nonce used to derive chain assigned `ProfessorId`
+    #[pallet::storage]
+    pub type IdNonce<T> = StorageValue<_, u64, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn get_offer)]
-    pub type Offers<T> = StorageMap<_, Twox64Concat, ProfessorId, OfferInfo>;
+    pub type Offers<T> = StorageMap<_, Twox64Concat, OfferId, OfferInfoFor<T>>;
+
+    /// Open offers made to professor
+    #[pallet::storage]
+    pub type ProfessorOffers<T> =
+        StorageDoubleMap<_, Twox64Concat, ProfessorId, Twox64Concat, OfferId, ()>;
+
+    /// Number of open offers made to professor
+    #[pallet::storage]
+    #[pallet::getter(fn get_professor_offer_count)]
+    pub type ProfessorOfferCount<T> = StorageMap<_, Twox64Concat, ProfessorId, u32, ValueQuery>;
+
+    /// Number of open offers made by university
+    #[pallet::storage]
+    #[pallet::getter(fn get_university_offer_count)]
+    pub type UniversityOfferCount<T> = StorageMap<_, Twox64Concat, UniversityId, u32, ValueQuery>;
+
+    /// Account and amount reserved for the open offer
+    #[pallet::storage]
+    #[pallet::getter(fn get_offer_deposit)]
+    pub type OfferDeposits<T> =
+        StorageMap<_, Twox64Concat, OfferId, (AccountIdOf<T>, BalanceOf<T>)>;
+
+    /// Open offers keyed by the block they expire on
+    #[pallet::storage]
+    pub type OfferExpiryLookup<T> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, OfferId, ()>;
+
+    /// First block whose expiring offers are yet to be processed
+    /// only set when expiries have been carried over
+    #[pallet::storage]
+    pub type ExpiryCursor<T> = StorageValue<_, BlockNumberFor<T>>;
+
+    /// Every university professor is or was affiliated with
+    /// faculty of university is mirrored by `UniversityProvider`
+    #[pallet::storage]
+    #[pallet::getter(fn get_affiliation)]
+    pub type Affiliations<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ProfessorId,
+        Twox64Concat,
+        UniversityId,
+        AffiliationFor<T>,
+    >;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -70,6 +170,16 @@ pub mod pallet {
         OfferMade(OfferId),
         /// Offer have been accepted
         OfferAccepted(OfferId),
+        /// Offer have been rejected by professor
+        OfferRejected(OfferId),
+        /// Offer have been withdrawn by university
+        OfferWithdrawn(OfferId),
+        /// Offer was not responded before it's expiry
+        OfferExpired(OfferId),
+        /// Professor have joined the university
+        AffiliationAdded(ProfessorId, UniversityId),
+        /// Professor have left the university
+        AffiliationEnded(ProfessorId, UniversityId),
     }
 
     #[pallet::error]
@@ -84,6 +194,20 @@ pub mod pallet {
         NoUniversity,
         /// No such offer
         NoOffer,
+        /// Offer with this id already exists
+        OfferExists,
+        /// Offer is past it's expiry
+        OfferExpired,
+        /// Offer validity is zero or longer than `MaxOfferValidity`
+        InvalidValidity,
+        /// Professor already have `MaxOpenOffersPerProfessor` open offers
+        TooManyProfessorOffers,
+        /// University already have `MaxOpenOffersPerUniversity` open offers
+        TooManyUniversityOffers,
+        /// Professor is already affiliated with this university
+        AlreadyAffiliated,
+        /// Professor is not affiliated with this university
+        NotAffiliated,
     }
 
     #[pallet::call]
@@ -91,15 +215,18 @@ pub mod pallet {
         #[pallet::weight(10_0000)]
         pub fn register_as_professor(
             origin: OriginFor<T>,
-            professor_id: ProfessorId,
+            professor_id: Option<ProfessorId>,
             info: NewProfessorParamFor,
         ) -> DispatchResult {
             // register this user as professor under professor_id
             // identity is the permanent identity of this user which will be hash of ipfs link
             // info is changable informarmation about professor ( eg: which university is he
             // associated to )
+            // if professor_id is not passed, chain will assign one
             let professor =
                 ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let professor_id = professor_id
+                .unwrap_or_else(|| <Self as IdGenerator<AccountIdOf<T>>>::generate_id(&professor));
             Self::ensure_professor_id_is_unique(&professor_id)?;
 
             let NewProfessorParam { info } = info;
@@ -114,25 +241,13 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn make_offer_for_uni(
             origin: OriginFor<T>,
-            offer_id: OfferId,
-            offer: OfferInfo,
+            offer: NewOfferParamFor<T>,
         ) -> DispatchResult {
+            // offer expires after `validity` blocks
+            // longer the validity larger the deposit reserved from university
+            // offer id is always assigned by chain and announced in `OfferMade`
             let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
-            let university = T::UniversityProvider::university_info(&offer.university)
-                .ok_or(Error::<T>::NoUniversity)?;
-            ensure!(
-                signer == university.admin,
-                Error::<T>::InsufficientPermission
-            );
-
-            // TODO:
-            // make some checks to see if this offer can be made. Example:
-            // - limit of offer to accept by professor
-            // - limit of offer to make by professor
-            // - if professor is open to work
-
-            Offers::<T>::insert(&offer_id, offer);
-            Self::deposit_event(Event::<T>::OfferMade(offer_id));
+            Self::do_make_offer(signer, offer)?;
 
             Ok(())
         }
@@ -145,8 +260,8 @@ pub mod pallet {
             // inclusion also staff need to pass the required info to match against one added by
             // university this is same as contract signing by staff side
             //
-            // also add the list of university in the professor info
-            // so we can query "get all university this professor is associated with"
+            // acceptance is recorded in `Affiliations` so we can query
+            // "get all university this professor is associated with"
             // without needing to iterate all university ( which will be impractical )
 
             let signer = ensure_signed(origin)?;
@@ -157,18 +272,90 @@ pub mod pallet {
                 signer == professor.professor,
                 Error::<T>::InsufficientPermission
             );
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() < offer_info.expires_on,
+                Error::<T>::OfferExpired
+            );
 
-            ensure!(Offers::<T>::contains_key(&offer_id), Error::<T>::NoOffer);
-            // TODO:
-            // do something to signify the acceptance of offer.
-            // example: adding the professor to university, assigning classes etc.
-            Offers::<T>::remove(&offer_id);
+            Self::close_offer(&offer_id, &offer_info);
+            let OfferInfo {
+                professor: professor_id,
+                university,
+                contract_file,
+                role,
+                faculty,
+                ..
+            } = offer_info;
+            Self::do_affiliate(professor_id, university, role, faculty, contract_file)?;
 
             Self::deposit_event(Event::<T>::OfferAccepted(offer_id));
 
             Ok(())
         }
 
+        #[pallet::weight(10_000)]
+        pub fn reject_offer(origin: OriginFor<T>, offer_id: OfferId) -> DispatchResult {
+            let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let offer_info = Self::get_offer(&offer_id).ok_or(Error::<T>::NoOffer)?;
+            let professor =
+                Self::get_professor(&offer_info.professor).ok_or(Error::<T>::NoProfessor)?;
+            ensure!(
+                signer == professor.professor,
+                Error::<T>::InsufficientPermission
+            );
+
+            Self::close_offer(&offer_id, &offer_info);
+            Self::deposit_event(Event::<T>::OfferRejected(offer_id));
+
+            Ok(())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn withdraw_offer(origin: OriginFor<T>, offer_id: OfferId) -> DispatchResult {
+            let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let offer_info = Self::get_offer(&offer_id).ok_or(Error::<T>::NoOffer)?;
+            ensure!(
+                T::UniversityProvider::university_admin(&offer_info.university) == Some(signer),
+                Error::<T>::InsufficientPermission
+            );
+
+            Self::close_offer(&offer_id, &offer_info);
+            Self::deposit_event(Event::<T>::OfferWithdrawn(offer_id));
+
+            Ok(())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn end_affiliation(
+            origin: OriginFor<T>,
+            professor_id: ProfessorId,
+            university_id: UniversityId,
+        ) -> DispatchResult {
+            // either professor or the university can terminate the affiliation
+            // affiliation is kept with the block it ended on
+            let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let professor = Self::get_professor(&professor_id).ok_or(Error::<T>::NoProfessor)?;
+            ensure!(
+                signer == professor.professor
+                    || T::UniversityProvider::university_admin(&university_id) == Some(signer),
+                Error::<T>::InsufficientPermission
+            );
+
+            Affiliations::<T>::try_mutate(&professor_id, &university_id, |affiliation| {
+                let affiliation = affiliation
+                    .as_mut()
+                    .filter(|affiliation| affiliation.is_active())
+                    .ok_or(Error::<T>::NotAffiliated)?;
+                affiliation.end = Some(<frame_system::Pallet<T>>::block_number());
+                Ok::<_, DispatchError>(())
+            })?;
+            T::UniversityProvider::note_disaffiliation(&university_id, &professor_id);
+
+            Self::deposit_event(Event::<T>::AffiliationEnded(professor_id, university_id));
+
+            Ok(())
+        }
+
         #[pallet::weight(10_000)]
         pub fn announce_thesis(
             origin: OriginFor<T>,
@@ -187,7 +374,231 @@ pub mod pallet {
         }
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(current_block_number: BlockNumberFor<T>) -> Weight {
+            Self::expire_offers(current_block_number)
+        }
+    }
+
+    impl<T: Config> IdGenerator<AccountIdOf<T>> for Pallet<T> {
+        const ENTITY: IdEntityKind = IdEntityKind::Professor;
+
+        fn next_nonce() -> u64 {
+            IdNonce::<T>::mutate(bump_nonce)
+        }
+    }
+
     impl<T: Config> Pallet<T> {
+        /// record professor as affiliated with university from current block
+        pub(super) fn do_affiliate(
+            professor_id: ProfessorId,
+            university_id: UniversityId,
+            role: AffiliationRole,
+            faculty: Option<FacultyId>,
+            contract: IpfsLink,
+        ) -> DispatchResult {
+            ensure!(
+                !Self::is_active_affiliation(&professor_id, &university_id),
+                Error::<T>::AlreadyAffiliated
+            );
+            T::UniversityProvider::note_affiliation(&university_id, &professor_id)?;
+
+            let affiliation = AffiliationFor::<T> {
+                role,
+                start: <frame_system::Pallet<T>>::block_number(),
+                end: None,
+                faculty,
+                contract,
+            };
+            Affiliations::<T>::insert(&professor_id, &university_id, affiliation);
+            Self::deposit_event(Event::<T>::AffiliationAdded(professor_id, university_id));
+
+            Ok(())
+        }
+
+        /// make an offer on behalf of university admin `signer`
+        /// deposit is reserved from `signer` and refunded once offer is resolved
+        pub(super) fn do_make_offer(
+            signer: AccountIdOf<T>,
+            offer: NewOfferParamFor<T>,
+        ) -> Result<OfferId, DispatchError> {
+            let university = T::UniversityProvider::university_info(&offer.university)
+                .ok_or(Error::<T>::NoUniversity)?;
+            ensure!(
+                signer == university.admin,
+                Error::<T>::InsufficientPermission
+            );
+            ensure!(
+                !offer.validity.is_zero() && offer.validity <= T::MaxOfferValidity::get(),
+                Error::<T>::InvalidValidity
+            );
+            ensure!(
+                Professors::<T>::contains_key(&offer.professor),
+                Error::<T>::NoProfessor
+            );
+            ensure!(
+                Self::get_professor_offer_count(&offer.professor)
+                    < T::MaxOpenOffersPerProfessor::get(),
+                Error::<T>::TooManyProfessorOffers
+            );
+            ensure!(
+                Self::get_university_offer_count(&offer.university)
+                    < T::MaxOpenOffersPerUniversity::get(),
+                Error::<T>::TooManyUniversityOffers
+            );
+
+            let offer_id = derive_uniq_id(IdEntityKind::Offer, Self::next_nonce(), &signer);
+            ensure!(
+                !Offers::<T>::contains_key(&offer_id),
+                Error::<T>::OfferExists
+            );
+
+            let deposit = Self::offer_deposit(offer.validity);
+            T::Currency::reserve(&signer, deposit)?;
+
+            let NewOfferParam {
+                professor,
+                university,
+                contract_file,
+                role,
+                faculty,
+                validity,
+            } = offer;
+            let expires_on = <frame_system::Pallet<T>>::block_number().saturating_add(validity);
+            let offer = OfferInfoFor::<T> {
+                professor,
+                university,
+                contract_file,
+                role,
+                faculty,
+                expires_on,
+            };
+
+            ProfessorOffers::<T>::insert(&offer.professor, &offer_id, ());
+            ProfessorOfferCount::<T>::mutate(&offer.professor, |count| *count += 1);
+            UniversityOfferCount::<T>::mutate(&offer.university, |count| *count += 1);
+            Offers::<T>::insert(&offer_id, offer);
+            OfferDeposits::<T>::insert(&offer_id, (signer, deposit));
+            OfferExpiryLookup::<T>::insert(expires_on, &offer_id, ());
+            Self::deposit_event(Event::<T>::OfferMade(offer_id));
+
+            Ok(offer_id)
+        }
+
+        pub fn is_active_affiliation(
+            professor_id: &ProfessorId,
+            university_id: &UniversityId,
+        ) -> bool {
+            Self::get_affiliation(professor_id, university_id)
+                .map(|affiliation| affiliation.is_active())
+                .unwrap_or(false)
+        }
+
+        /// page of open offers made to professor
+        pub fn professor_offers_page(
+            professor_id: &ProfessorId,
+            start: Option<OfferId>,
+            limit: u32,
+        ) -> Page<OfferId, OfferInfoFor<T>> {
+            let Page { items, next } =
+                double_map_page::<ProfessorOffers<T>, _, _, _>(professor_id.clone(), start, limit);
+
+            Page {
+                items: items
+                    .into_iter()
+                    .filter_map(|(offer_id, ())| {
+                        Self::get_offer(&offer_id).map(|offer_info| (offer_id, offer_info))
+                    })
+                    .collect(),
+                next,
+            }
+        }
+
+        /// deposit to reserve for offer valid for `validity` blocks
+        pub fn offer_deposit(validity: BlockNumberFor<T>) -> BalanceOf<T> {
+            let blocks: u32 = validity.saturated_into();
+            T::OfferDepositPerBlock::get()
+                .saturating_mul(blocks.into())
+                .saturating_add(T::OfferDepositBase::get())
+        }
+
+        /// remove the resolved offer and refund it's deposit
+        fn close_offer(offer_id: &OfferId, offer_info: &OfferInfoFor<T>) {
+            Offers::<T>::remove(offer_id);
+            OfferExpiryLookup::<T>::remove(offer_info.expires_on, offer_id);
+            ProfessorOffers::<T>::remove(&offer_info.professor, offer_id);
+            ProfessorOfferCount::<T>::mutate(&offer_info.professor, |count| {
+                *count = count.saturating_sub(1)
+            });
+            UniversityOfferCount::<T>::mutate(&offer_info.university, |count| {
+                *count = count.saturating_sub(1)
+            });
+            if let Some((depositor, deposit)) = OfferDeposits::<T>::take(offer_id) {
+                T::Currency::unreserve(&depositor, deposit);
+            }
+        }
+
+        /// expire all offers that were not resolved upto `current_block_number`
+        ///
+        /// at most `MaxOfferExpiriesPerBlock` offers are expired and at most
+        /// same number of blocks are scanned. Whatever is left is carried over
+        /// to next block through `ExpiryCursor`
+        fn expire_offers(current_block_number: BlockNumberFor<T>) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let max_expiries = T::MaxOfferExpiriesPerBlock::get();
+            let mut budget = max_expiries;
+            let mut scanned = 0_u32;
+
+            let cursor = ExpiryCursor::<T>::get();
+            let mut weight_consumed = db_weight.reads(1);
+
+            let mut block_number = cursor.unwrap_or(current_block_number);
+            while block_number <= current_block_number && budget > 0 && scanned < max_expiries {
+                let mut expiring = OfferExpiryLookup::<T>::iter_key_prefix(block_number)
+                    .take(budget as usize + 1)
+                    .collect::<Vec<_>>();
+                let drained = expiring.len() <= budget as usize;
+                expiring.truncate(budget as usize);
+                budget -= expiring.len() as u32;
+
+                // per offer: 4 reads of offer, deposit & both counters
+                // 7 writes to offer, lookup, index, counters, deposit & reserved balance
+                let count = expiring.len() as u64;
+                weight_consumed += db_weight.reads_writes(1 + 4 * count, 7 * count);
+
+                for offer_id in expiring {
+                    match Self::get_offer(&offer_id) {
+                        Some(offer_info) => {
+                            Self::close_offer(&offer_id, &offer_info);
+                            Self::deposit_event(Event::<T>::OfferExpired(offer_id));
+                        }
+                        None => OfferExpiryLookup::<T>::remove(block_number, &offer_id),
+                    }
+                }
+
+                if !drained {
+                    break;
+                }
+
+                block_number = block_number.saturating_add(One::one());
+                scanned += 1;
+            }
+
+            if block_number > current_block_number {
+                // nothing is left to carry over
+                if cursor.is_some() {
+                    ExpiryCursor::<T>::kill();
+                    weight_consumed += db_weight.writes(1);
+                }
+            } else if cursor != Some(block_number) {
+                ExpiryCursor::<T>::put(block_number);
+                weight_consumed += db_weight.writes(1);
+            }
+
+            weight_consumed
+        }
+
         fn ensure_professor_id_is_unique(professor_id: &ProfessorId) -> DispatchResult {
             ensure!(
                 !Professors::<T>::contains_key(professor_id),
@@ -206,8 +617,31 @@ use crate::{ProfessorId, ProfessorInfoFor};
 impl<T: Config> traits::pallet_provider::ProfessorProvider for Pallet<T> {
     type ProfessorId = crate::ProfessorId;
     type ProfessorInfo = crate::ProfessorInfoFor<T>;
+    type AffiliationInfo = crate::AffiliationFor<T>;
+    type FrameConfig = T;
 
     fn professor_info(professor_id: &Self::ProfessorId) -> Option<Self::ProfessorInfo> {
         crate::Pallet::<T>::get_professor(professor_id)
     }
+
+    fn affiliation_info(
+        professor_id: &Self::ProfessorId,
+        university_id: &crate::UniversityId,
+    ) -> Option<Self::AffiliationInfo> {
+        crate::Pallet::<T>::get_affiliation(professor_id, university_id)
+    }
+
+    fn is_affiliated(
+        professor_id: &Self::ProfessorId,
+        university_id: &crate::UniversityId,
+    ) -> bool {
+        crate::Pallet::<T>::is_active_affiliation(professor_id, university_id)
+    }
+
+    fn make_offer(
+        who: &crate::AccountIdOf<T>,
+        offer: crate::NewOfferParamFor<T>,
+    ) -> Result<crate::OfferId, frame_support::dispatch::DispatchError> {
+        crate::Pallet::<T>::do_make_offer(who.clone(), offer)
+    }
 }
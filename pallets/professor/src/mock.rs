@@ -0,0 +1,218 @@
+use crate as pallet_professor;
+use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
+    traits::{ConstU16, ConstU32, ConstU64},
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+use std::cell::RefCell;
+use traits::pallet_provider::{StudentProvider, UniversityProvider};
+use types::{
+    primitives::{StdIpfsLink, UniqId},
+    professor::ProfessorId,
+    student::{ApplicationId, StudentId},
+    university::{University, UniversityId},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+pub const UNIVERSITY_ADMIN: AccountId = 1;
+pub const PROFESSOR_ACCOUNT: AccountId = 2;
+pub const OTHER_ACCOUNT: AccountId = 3;
+
+pub const INITIAL_BALANCE: u64 = 1_000;
+pub const OFFER_DEPOSIT_BASE: u64 = 10;
+pub const OFFER_DEPOSIT_PER_BLOCK: u64 = 1;
+pub const MAX_OFFER_VALIDITY: u64 = 10;
+pub const MAX_OFFERS_PER_PROFESSOR: u32 = 2;
+pub const MAX_OFFERS_PER_UNIVERSITY: u32 = 3;
+pub const MAX_EXPIRIES_PER_BLOCK: u32 = 2;
+
+/// universities known to `MockUniversityProvider`
+/// both are administered by `UNIVERSITY_ADMIN`
+pub const UNIVERSITY: UniqId = UniqId::new([1; 32]);
+pub const OTHER_UNIVERSITY: UniqId = UniqId::new([2; 32]);
+pub const PROFESSOR: UniqId = UniqId::new([6; 32]);
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        Professor: pallet_professor,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+impl pallet_professor::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type UniversityProvider = MockUniversityProvider;
+    type StudentProvider = MockStudentProvider;
+    type Currency = Balances;
+    type OfferDepositBase = ConstU64<OFFER_DEPOSIT_BASE>;
+    type OfferDepositPerBlock = ConstU64<OFFER_DEPOSIT_PER_BLOCK>;
+    type MaxOfferValidity = ConstU64<MAX_OFFER_VALIDITY>;
+    type MaxOfferExpiriesPerBlock = ConstU32<MAX_EXPIRIES_PER_BLOCK>;
+    type MaxOpenOffersPerProfessor = ConstU32<MAX_OFFERS_PER_PROFESSOR>;
+    type MaxOpenOffersPerUniversity = ConstU32<MAX_OFFERS_PER_UNIVERSITY>;
+}
+
+pub fn ipfs_link() -> StdIpfsLink {
+    StdIpfsLink::new(Default::default())
+}
+
+thread_local! {
+    static FACULTY: RefCell<Vec<(UniversityId, ProfessorId)>> = RefCell::new(Vec::new());
+}
+
+/// whether professor is in the faculty mirrored by `MockUniversityProvider`
+pub fn is_in_faculty(university_id: &UniversityId, professor_id: &ProfessorId) -> bool {
+    FACULTY.with(|faculty| faculty.borrow().contains(&(*university_id, *professor_id)))
+}
+
+/// faculty is kept in memory of the test thread
+pub struct MockUniversityProvider;
+impl UniversityProvider for MockUniversityProvider {
+    type UniversityId = UniversityId;
+    type UniversityInfo = University<AccountId>;
+    type FrameConfig = Test;
+
+    fn university_info(university_id: &Self::UniversityId) -> Option<Self::UniversityInfo> {
+        [UNIVERSITY, OTHER_UNIVERSITY]
+            .contains(university_id)
+            .then(|| University {
+                admin: UNIVERSITY_ADMIN,
+                permanent_info: ipfs_link(),
+            })
+    }
+
+    fn university_admin(university_id: &Self::UniversityId) -> Option<AccountId> {
+        Self::university_info(university_id).map(|info| info.admin)
+    }
+
+    fn note_affiliation(
+        university_id: &Self::UniversityId,
+        professor_id: &ProfessorId,
+    ) -> DispatchResult {
+        if Self::university_info(university_id).is_none() {
+            return Err(DispatchError::Other("no university"));
+        }
+        FACULTY.with(|faculty| faculty.borrow_mut().push((*university_id, *professor_id)));
+        Ok(())
+    }
+
+    fn note_disaffiliation(university_id: &Self::UniversityId, professor_id: &ProfessorId) {
+        FACULTY.with(|faculty| {
+            faculty
+                .borrow_mut()
+                .retain(|member| member != &(*university_id, *professor_id))
+        });
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register_university_for_benchmark(_: AccountId) -> Self::UniversityId {
+        UNIVERSITY
+    }
+}
+
+/// students are not used by professor tests
+pub struct MockStudentProvider;
+impl StudentProvider for MockStudentProvider {
+    type StudentId = StudentId;
+    type StudentInfo = ();
+    type ApplicationId = ApplicationId;
+    type ApplicationInfo = ();
+    type FrameConfig = Test;
+
+    fn student_info(_: &StudentId) -> Option<()> {
+        None
+    }
+
+    fn student_of(_: &AccountId) -> Option<StudentId> {
+        None
+    }
+
+    fn note_enrollment(_: &StudentId, _: &UniversityId) -> DispatchResult {
+        Err(DispatchError::Other("no student"))
+    }
+
+    fn note_graduation(_: &StudentId, _: &UniversityId) -> DispatchResult {
+        Err(DispatchError::Other("no student"))
+    }
+
+    fn note_withdrawal(_: &StudentId, _: &UniversityId) -> DispatchResult {
+        Err(DispatchError::Other("no student"))
+    }
+
+    fn application_info(_: &ApplicationId) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("no application"))
+    }
+
+    fn close_application(_: &ApplicationId) -> DispatchResult {
+        Err(DispatchError::Other("no application"))
+    }
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(UNIVERSITY_ADMIN, INITIAL_BALANCE)],
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = storage.into();
+    // events are not deposited on genesis block
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,584 @@
+use crate::{
+    mock::*, Affiliations, Error, Event, ExpiryCursor, NewOfferParamFor, OfferExpiryLookup, OfferId,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Hooks, ReservableCurrency},
+};
+use traits::pallet_provider::ProfessorProvider;
+use types::{
+    primitives::UniqId,
+    professor::{AffiliationRole, NewOfferParam, NewProfessorParam, ProfessorId},
+    university::UniversityId,
+};
+
+fn register_professor(account: AccountId, professor_id: ProfessorId) {
+    assert_ok!(Professor::register_as_professor(
+        RuntimeOrigin::signed(account),
+        Some(professor_id),
+        NewProfessorParam { info: ipfs_link() },
+    ));
+}
+
+fn offer_param(
+    professor: ProfessorId,
+    university: UniversityId,
+    validity: u64,
+) -> NewOfferParamFor<Test> {
+    NewOfferParam {
+        professor,
+        university,
+        contract_file: ipfs_link(),
+        role: AffiliationRole::Lecturer,
+        faculty: None,
+        validity,
+    }
+}
+
+/// make an offer from `university` and return the id assigned by chain
+fn make_offer(professor: ProfessorId, university: UniversityId, validity: u64) -> OfferId {
+    assert_ok!(Professor::make_offer_for_uni(
+        RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+        offer_param(professor, university, validity),
+    ));
+
+    System::events()
+        .into_iter()
+        .rev()
+        .find_map(|record| match record.event {
+            RuntimeEvent::Professor(Event::OfferMade(offer_id)) => Some(offer_id),
+            _ => None,
+        })
+        .expect("offer was made")
+}
+
+/// professor registered by `OTHER_ACCOUNT`
+fn other_professor(seed: u8) -> ProfessorId {
+    let professor_id = UniqId::new([seed; 32]);
+    register_professor(OTHER_ACCOUNT, professor_id);
+    professor_id
+}
+
+fn reserved() -> u64 {
+    Balances::reserved_balance(&UNIVERSITY_ADMIN)
+}
+
+fn affiliate(university: UniversityId) {
+    let offer_id = make_offer(PROFESSOR, university, MAX_OFFER_VALIDITY);
+    assert_ok!(Professor::accept_offer(
+        RuntimeOrigin::signed(PROFESSOR_ACCOUNT),
+        offer_id,
+    ));
+}
+
+#[test]
+fn accepted_offer_records_affiliation_and_faculty() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        let offer_id = make_offer(PROFESSOR, UNIVERSITY, MAX_OFFER_VALIDITY);
+
+        System::set_block_number(3);
+        assert_ok!(Professor::accept_offer(
+            RuntimeOrigin::signed(PROFESSOR_ACCOUNT),
+            offer_id,
+        ));
+
+        let affiliation = Professor::get_affiliation(&PROFESSOR, &UNIVERSITY).unwrap();
+        assert_eq!(affiliation.role, AffiliationRole::Lecturer);
+        assert_eq!(affiliation.start, 3);
+        assert_eq!(affiliation.end, None);
+        assert_eq!(affiliation.contract, ipfs_link());
+        assert!(Professor::get_offer(&offer_id).is_none());
+
+        assert!(is_in_faculty(&UNIVERSITY, &PROFESSOR));
+        assert!(<Professor as ProfessorProvider>::is_affiliated(
+            &PROFESSOR,
+            &UNIVERSITY
+        ));
+        System::assert_has_event(Event::AffiliationAdded(PROFESSOR, UNIVERSITY).into());
+        System::assert_last_event(Event::OfferAccepted(offer_id).into());
+    });
+}
+
+#[test]
+fn offer_can_only_be_accepted_by_professor() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        let offer_id = make_offer(PROFESSOR, UNIVERSITY, MAX_OFFER_VALIDITY);
+
+        assert_noop!(
+            Professor::accept_offer(RuntimeOrigin::signed(OTHER_ACCOUNT), offer_id),
+            Error::<Test>::InsufficientPermission
+        );
+        assert!(!is_in_faculty(&UNIVERSITY, &PROFESSOR));
+    });
+}
+
+#[test]
+fn professor_cannot_be_affiliated_twice_with_same_university() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        affiliate(UNIVERSITY);
+
+        let offer_id = make_offer(PROFESSOR, UNIVERSITY, MAX_OFFER_VALIDITY);
+        assert_noop!(
+            Professor::accept_offer(RuntimeOrigin::signed(PROFESSOR_ACCOUNT), offer_id),
+            Error::<Test>::AlreadyAffiliated
+        );
+
+        // affiliation with other university is independent
+        affiliate(OTHER_UNIVERSITY);
+        assert!(Professor::is_active_affiliation(&PROFESSOR, &UNIVERSITY));
+        assert!(Professor::is_active_affiliation(
+            &PROFESSOR,
+            &OTHER_UNIVERSITY
+        ));
+        assert_eq!(Affiliations::<Test>::iter_prefix(&PROFESSOR).count(), 2);
+    });
+}
+
+#[test]
+fn ended_affiliation_is_kept_and_removed_from_faculty() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        affiliate(UNIVERSITY);
+
+        System::set_block_number(5);
+        assert_ok!(Professor::end_affiliation(
+            RuntimeOrigin::signed(PROFESSOR_ACCOUNT),
+            PROFESSOR,
+            UNIVERSITY,
+        ));
+
+        let affiliation =
+            <Professor as ProfessorProvider>::affiliation_info(&PROFESSOR, &UNIVERSITY).unwrap();
+        assert_eq!(affiliation.start, 1);
+        assert_eq!(affiliation.end, Some(5));
+        assert!(!<Professor as ProfessorProvider>::is_affiliated(
+            &PROFESSOR,
+            &UNIVERSITY
+        ));
+        assert!(!is_in_faculty(&UNIVERSITY, &PROFESSOR));
+        System::assert_last_event(Event::AffiliationEnded(PROFESSOR, UNIVERSITY).into());
+
+        assert_noop!(
+            Professor::end_affiliation(
+                RuntimeOrigin::signed(PROFESSOR_ACCOUNT),
+                PROFESSOR,
+                UNIVERSITY,
+            ),
+            Error::<Test>::NotAffiliated
+        );
+
+        // professor can join again once previous affiliation have ended
+        affiliate(UNIVERSITY);
+        assert_eq!(
+            Professor::get_affiliation(&PROFESSOR, &UNIVERSITY)
+                .unwrap()
+                .start,
+            5
+        );
+        assert!(is_in_faculty(&UNIVERSITY, &PROFESSOR));
+    });
+}
+
+#[test]
+fn affiliation_can_be_ended_by_university_admin_only() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        affiliate(UNIVERSITY);
+
+        assert_noop!(
+            Professor::end_affiliation(RuntimeOrigin::signed(OTHER_ACCOUNT), PROFESSOR, UNIVERSITY),
+            Error::<Test>::InsufficientPermission
+        );
+
+        assert_ok!(Professor::end_affiliation(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            PROFESSOR,
+            UNIVERSITY,
+        ));
+        assert!(!Professor::is_active_affiliation(&PROFESSOR, &UNIVERSITY));
+        assert!(!is_in_faculty(&UNIVERSITY, &PROFESSOR));
+    });
+}
+
+#[test]
+fn offer_deposit_scales_with_validity() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        assert_eq!(
+            Professor::offer_deposit(4),
+            OFFER_DEPOSIT_BASE + 4 * OFFER_DEPOSIT_PER_BLOCK
+        );
+
+        let short = make_offer(PROFESSOR, UNIVERSITY, 1);
+        assert_eq!(reserved(), Professor::offer_deposit(1));
+        let long = make_offer(PROFESSOR, OTHER_UNIVERSITY, MAX_OFFER_VALIDITY);
+        assert_eq!(
+            reserved(),
+            Professor::offer_deposit(1) + Professor::offer_deposit(MAX_OFFER_VALIDITY)
+        );
+
+        assert_eq!(
+            Professor::get_offer_deposit(&short),
+            Some((UNIVERSITY_ADMIN, Professor::offer_deposit(1)))
+        );
+        assert_eq!(
+            Professor::get_offer_deposit(&long),
+            Some((
+                UNIVERSITY_ADMIN,
+                Professor::offer_deposit(MAX_OFFER_VALIDITY)
+            ))
+        );
+        assert_eq!(
+            Professor::get_offer(&long).unwrap().expires_on,
+            1 + MAX_OFFER_VALIDITY
+        );
+    });
+}
+
+#[test]
+fn offer_validity_is_bounded() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+
+        for validity in [0, MAX_OFFER_VALIDITY + 1] {
+            assert_noop!(
+                Professor::make_offer_for_uni(
+                    RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                    offer_param(PROFESSOR, UNIVERSITY, validity),
+                ),
+                Error::<Test>::InvalidValidity
+            );
+        }
+    });
+}
+
+#[test]
+fn offer_without_enough_balance_for_deposit_is_not_made() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        assert_ok!(Balances::reserve(
+            &UNIVERSITY_ADMIN,
+            INITIAL_BALANCE - OFFER_DEPOSIT_BASE
+        ));
+
+        assert!(Professor::make_offer_for_uni(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            offer_param(PROFESSOR, UNIVERSITY, 1),
+        )
+        .is_err());
+        assert_eq!(Professor::get_professor_offer_count(&PROFESSOR), 0);
+    });
+}
+
+#[test]
+fn accepted_offer_refunds_deposit() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        affiliate(UNIVERSITY);
+
+        assert_eq!(reserved(), 0);
+        assert_eq!(Balances::free_balance(&UNIVERSITY_ADMIN), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn rejected_offer_refunds_deposit() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        let offer_id = make_offer(PROFESSOR, UNIVERSITY, 5);
+
+        assert_noop!(
+            Professor::reject_offer(RuntimeOrigin::signed(UNIVERSITY_ADMIN), offer_id),
+            Error::<Test>::InsufficientPermission
+        );
+
+        assert_ok!(Professor::reject_offer(
+            RuntimeOrigin::signed(PROFESSOR_ACCOUNT),
+            offer_id,
+        ));
+
+        assert!(Professor::get_offer(&offer_id).is_none());
+        assert!(Professor::get_offer_deposit(&offer_id).is_none());
+        assert!(!OfferExpiryLookup::<Test>::contains_key(6, &offer_id));
+        assert_eq!(reserved(), 0);
+        assert!(!Professor::is_active_affiliation(&PROFESSOR, &UNIVERSITY));
+        System::assert_last_event(Event::OfferRejected(offer_id).into());
+    });
+}
+
+#[test]
+fn withdrawn_offer_refunds_deposit() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        let offer_id = make_offer(PROFESSOR, UNIVERSITY, 5);
+
+        assert_noop!(
+            Professor::withdraw_offer(RuntimeOrigin::signed(PROFESSOR_ACCOUNT), offer_id),
+            Error::<Test>::InsufficientPermission
+        );
+
+        assert_ok!(Professor::withdraw_offer(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            offer_id,
+        ));
+
+        assert!(Professor::get_offer(&offer_id).is_none());
+        assert!(Professor::get_offer_deposit(&offer_id).is_none());
+        assert_eq!(reserved(), 0);
+        System::assert_last_event(Event::OfferWithdrawn(offer_id).into());
+
+        assert_noop!(
+            Professor::accept_offer(RuntimeOrigin::signed(PROFESSOR_ACCOUNT), offer_id),
+            Error::<Test>::NoOffer
+        );
+    });
+}
+
+#[test]
+fn offer_cannot_be_accepted_on_expiry() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        let offer_id = make_offer(PROFESSOR, UNIVERSITY, 3);
+
+        System::set_block_number(4);
+        assert_noop!(
+            Professor::accept_offer(RuntimeOrigin::signed(PROFESSOR_ACCOUNT), offer_id),
+            Error::<Test>::OfferExpired
+        );
+    });
+}
+
+#[test]
+fn expired_offer_is_pruned_and_refunded() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        let offer_id = make_offer(PROFESSOR, UNIVERSITY, 3);
+
+        // nothing to expire before `expires_on`
+        Professor::on_initialize(3);
+        assert!(Professor::get_offer(&offer_id).is_some());
+
+        System::set_block_number(4);
+        Professor::on_initialize(4);
+
+        assert!(Professor::get_offer(&offer_id).is_none());
+        assert!(!OfferExpiryLookup::<Test>::contains_key(4, &offer_id));
+        assert_eq!(Professor::get_professor_offer_count(&PROFESSOR), 0);
+        assert_eq!(Professor::get_university_offer_count(&UNIVERSITY), 0);
+        assert_eq!(reserved(), 0);
+        System::assert_last_event(Event::OfferExpired(offer_id).into());
+    });
+}
+
+#[test]
+fn expiries_over_limit_are_carried_over() {
+    new_test_ext().execute_with(|| {
+        let offers = (7..7 + MAX_EXPIRIES_PER_BLOCK as u8 + 1)
+            .map(|seed| make_offer(other_professor(seed), UNIVERSITY, 3))
+            .collect::<Vec<_>>();
+
+        System::set_block_number(4);
+        Professor::on_initialize(4);
+        let open = offers
+            .iter()
+            .filter(|offer_id| Professor::get_offer(offer_id).is_some())
+            .count();
+        assert_eq!(open, 1);
+        assert_eq!(ExpiryCursor::<Test>::get(), Some(4));
+
+        System::set_block_number(5);
+        Professor::on_initialize(5);
+        assert!(offers
+            .iter()
+            .all(|offer_id| Professor::get_offer(offer_id).is_none()));
+        assert_eq!(ExpiryCursor::<Test>::get(), None);
+        assert_eq!(reserved(), 0);
+    });
+}
+
+#[test]
+fn offer_ids_are_assigned_by_chain() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        let first = make_offer(PROFESSOR, UNIVERSITY, 5);
+        let second = make_offer(PROFESSOR, UNIVERSITY, 5);
+
+        assert_ne!(first, second);
+        assert!(Professor::get_offer(&first).is_some());
+        assert!(Professor::get_offer(&second).is_some());
+    });
+}
+
+#[test]
+fn offer_is_made_only_to_registered_professor() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Professor::make_offer_for_uni(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                offer_param(PROFESSOR, UNIVERSITY, 5),
+            ),
+            Error::<Test>::NoProfessor
+        );
+        assert_eq!(reserved(), 0);
+    });
+}
+
+#[test]
+fn offer_is_made_only_by_university_admin() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        assert_noop!(
+            Professor::make_offer_for_uni(
+                RuntimeOrigin::signed(OTHER_ACCOUNT),
+                offer_param(PROFESSOR, UNIVERSITY, 5),
+            ),
+            Error::<Test>::InsufficientPermission
+        );
+    });
+}
+
+#[test]
+fn open_offers_are_indexed_by_professor() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        let other = other_professor(7);
+        let first = make_offer(PROFESSOR, UNIVERSITY, 5);
+        let second = make_offer(PROFESSOR, OTHER_UNIVERSITY, 5);
+        let unrelated = make_offer(other, UNIVERSITY, 5);
+
+        assert!(ProfessorOffers::<Test>::contains_key(&PROFESSOR, &first));
+        assert!(ProfessorOffers::<Test>::contains_key(&PROFESSOR, &second));
+        assert!(!ProfessorOffers::<Test>::contains_key(
+            &PROFESSOR, &unrelated
+        ));
+
+        let page = Professor::professor_offers_page(&PROFESSOR, None, 10);
+        let mut listed = page
+            .items
+            .iter()
+            .map(|(offer_id, _)| *offer_id)
+            .collect::<Vec<_>>();
+        let mut expected = vec![first, second];
+        listed.sort();
+        expected.sort();
+        assert_eq!(listed, expected);
+        assert_eq!(page.next, None);
+
+        // resolved offer leaves the index
+        assert_ok!(Professor::reject_offer(
+            RuntimeOrigin::signed(PROFESSOR_ACCOUNT),
+            first,
+        ));
+        assert!(!ProfessorOffers::<Test>::contains_key(&PROFESSOR, &first));
+        assert_eq!(
+            Professor::professor_offers_page(&PROFESSOR, None, 10).items,
+            vec![(second, Professor::get_offer(&second).unwrap())]
+        );
+    });
+}
+
+#[test]
+fn open_offers_per_professor_are_capped() {
+    new_test_ext().execute_with(|| {
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        let offers = (0..MAX_OFFERS_PER_PROFESSOR)
+            .map(|_| make_offer(PROFESSOR, UNIVERSITY, 5))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Professor::get_professor_offer_count(&PROFESSOR),
+            MAX_OFFERS_PER_PROFESSOR
+        );
+
+        assert_noop!(
+            Professor::make_offer_for_uni(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                offer_param(PROFESSOR, OTHER_UNIVERSITY, 5),
+            ),
+            Error::<Test>::TooManyProfessorOffers
+        );
+
+        // resolving an offer frees a slot
+        assert_ok!(Professor::withdraw_offer(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            offers[0],
+        ));
+        make_offer(PROFESSOR, OTHER_UNIVERSITY, 5);
+    });
+}
+
+#[test]
+fn open_offers_per_university_are_capped() {
+    new_test_ext().execute_with(|| {
+        let offers = (7..7 + MAX_OFFERS_PER_UNIVERSITY as u8)
+            .map(|seed| make_offer(other_professor(seed), UNIVERSITY, 5))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Professor::get_university_offer_count(&UNIVERSITY),
+            MAX_OFFERS_PER_UNIVERSITY
+        );
+
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+        assert_noop!(
+            Professor::make_offer_for_uni(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                offer_param(PROFESSOR, UNIVERSITY, 5),
+            ),
+            Error::<Test>::TooManyUniversityOffers
+        );
+
+        // cap is kept per university
+        make_offer(PROFESSOR, OTHER_UNIVERSITY, 5);
+
+        System::set_block_number(6);
+        Professor::on_initialize(6);
+        System::set_block_number(7);
+        Professor::on_initialize(7);
+        assert!(offers
+            .iter()
+            .all(|offer_id| Professor::get_offer(offer_id).is_none()));
+        assert_eq!(Professor::get_university_offer_count(&UNIVERSITY), 0);
+        make_offer(PROFESSOR, UNIVERSITY, 5);
+    });
+}
+
+#[test]
+fn offer_made_through_provider_carries_faculty_to_affiliation() {
+    new_test_ext().execute_with(|| {
+        let faculty = UniqId::new([9; 32]);
+        register_professor(PROFESSOR_ACCOUNT, PROFESSOR);
+
+        assert_noop!(
+            <Professor as ProfessorProvider>::make_offer(
+                &OTHER_ACCOUNT,
+                offer_param(PROFESSOR, UNIVERSITY, 5)
+            ),
+            Error::<Test>::InsufficientPermission
+        );
+
+        let offer_id = <Professor as ProfessorProvider>::make_offer(
+            &UNIVERSITY_ADMIN,
+            NewOfferParam {
+                faculty: Some(faculty),
+                ..offer_param(PROFESSOR, UNIVERSITY, 5)
+            },
+        )
+        .unwrap();
+        assert_eq!(reserved(), Professor::offer_deposit(5));
+        System::assert_last_event(Event::OfferMade(offer_id).into());
+
+        assert_ok!(Professor::accept_offer(
+            RuntimeOrigin::signed(PROFESSOR_ACCOUNT),
+            offer_id,
+        ));
+        assert_eq!(
+            Professor::get_affiliation(&PROFESSOR, &UNIVERSITY)
+                .unwrap()
+                .faculty,
+            Some(faculty)
+        );
+        assert_eq!(reserved(), 0);
+    });
+}
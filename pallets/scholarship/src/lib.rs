@@ -7,7 +7,7 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
     use traits::pallet_provider as pallet_provider_traits;
-    use types::scholarship::*;
+    pub(super) use types::scholarship::*;
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
@@ -25,15 +25,23 @@ pub mod pallet {
     }
 
     #[pallet::storage]
-    #[pallet::getter(fn something)]
-    pub type Something<T> = StorageValue<_, u32>;
+    #[pallet::getter(fn get_scholarship)]
+    pub type Scholarships<T> = StorageMap<_, Twox64Concat, ScholarshipId, ScholarshipInfoFor<T>>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {}
+    pub enum Event<T: Config> {
+        /// New scholarship have been registered
+        ScholarshipRegistered(ScholarshipId),
+    }
 
     #[pallet::error]
-    pub enum Error<T> {}
+    pub enum Error<T> {
+        /// Origin cannot perform this action
+        InsufficientPermission,
+        /// Scholarship with this id already exists
+        ScholarshipExists,
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -41,7 +49,7 @@ pub mod pallet {
         pub fn register_scholarship(
             origin: OriginFor<T>,
             scholarship_id: ScholarshipId,
-            info: (),
+            info: NewScholarshipParam,
         ) -> DispatchResult {
             // create a scholarship grant under scholarship_id
             //
@@ -49,6 +57,24 @@ pub mod pallet {
             // - no. of student to provide this grant to
             // - fund balance to have enough amount to grant that much credit
             // - optional list of university / faculty to whom to provide this scholarship
+            let host = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            ensure!(
+                !Scholarships::<T>::contains_key(&scholarship_id),
+                Error::<T>::ScholarshipExists
+            );
+
+            let NewScholarshipParam {
+                max_grantees,
+                details,
+            } = info;
+            let scholarship = ScholarshipInfoFor::<T> {
+                host,
+                max_grantees,
+                details,
+            };
+
+            Scholarships::<T>::insert(&scholarship_id, scholarship);
+            Self::deposit_event(Event::<T>::ScholarshipRegistered(scholarship_id));
 
             Ok(())
         }
@@ -56,7 +82,10 @@ pub mod pallet {
 }
 
 impl<T: Config> traits::pallet_provider::ScholarshipProvider for Pallet<T> {
-    fn get_scholarship_info() {
-        todo!()
+    type ScholarshipId = crate::ScholarshipId;
+    type ScholarshipInfo = crate::ScholarshipInfoFor<T>;
+
+    fn scholarship_info(scholarship_id: &Self::ScholarshipId) -> Option<Self::ScholarshipInfo> {
+        crate::Pallet::<T>::get_scholarship(scholarship_id)
     }
 }
@@ -2,13 +2,26 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{pallet_prelude::*, Twox64Concat};
     use frame_system::pallet_prelude::*;
-    use traits::pallet_provider as pallet_provider_traits;
+    use traits::{
+        id_generator::{bump_nonce, derive_uniq_id, IdGenerator},
+        pallet_provider as pallet_provider_traits,
+    };
     pub(super) use types::student::*;
-    use types::{primitives::StdIpfsLink as IpfsLink, student::*, university::UniversityId};
+    use types::{
+        primitives::{AccountIdOf, IdEntityKind, StdIpfsLink as IpfsLink},
+        student::*,
+        university::UniversityId,
+    };
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
@@ -22,14 +35,30 @@ pub mod pallet {
         type UniversityProvider: pallet_provider_traits::UniversityProvider;
     }
 
+    /// Profile of registered students
+    #[pallet::storage]
+    #[pallet::getter(fn get_student)]
+    pub type Students<T> = StorageMap<_, Twox64Concat, StudentId, StudentInfoFor<T>>;
+
+    /// Student id registered by an account
+    #[pallet::storage]
+    #[pallet::getter(fn get_student_of)]
+    pub type StudentOf<T> = StorageMap<_, Twox64Concat, AccountIdOf<T>, StudentId>;
+
     #[pallet::storage]
     #[pallet::getter(fn get_application)]
     pub type Applications<T> = StorageMap<_, Twox64Concat, ApplicationId, ApplicationInfoFor<T>>;
 
+    /// Nonce used to derive chain assigned `ApplicationId` and `StudentId`
+    #[pallet::storage]
+    pub type IdNonce<T> = StorageValue<_, u64, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         ApplicationSubmitted(ApplicationId),
+        /// New student have been registered
+        StudentRegistered(StudentId),
     }
 
     #[pallet::error]
@@ -38,19 +67,73 @@ pub mod pallet {
         InsufficientPermission,
         /// No such enrollment application
         NoApplication,
+        /// Application with this id already exists
+        ApplicationExists,
+        /// Student with this id already exists
+        StudentExists,
+        /// Account have already registered as student
+        AlreadyRegistered,
+        /// Account is not registered as student
+        NoStudent,
+        /// Student is enrolled in maximum number of universities
+        TooManyEnrollments,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        #[pallet::weight(10_000)]
+        pub fn register_as_student(
+            origin: OriginFor<T>,
+            student_id: Option<StudentId>,
+            info: NewStudentParam,
+        ) -> DispatchResult {
+            // an account can only have one student profile
+            // if student_id is not passed, chain will assign one
+            let account = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            ensure!(
+                !StudentOf::<T>::contains_key(&account),
+                Error::<T>::AlreadyRegistered
+            );
+            let student_id = student_id.unwrap_or_else(|| {
+                derive_uniq_id(IdEntityKind::Student, Self::next_nonce(), &account)
+            });
+            ensure!(
+                !Students::<T>::contains_key(&student_id),
+                Error::<T>::StudentExists
+            );
+
+            let NewStudentParam { profile } = info;
+            let student_info = StudentInfoFor::<T> {
+                account: account.clone(),
+                profile,
+                enrollments: Default::default(),
+                status: StudentStatus::Registered,
+            };
+
+            Students::<T>::insert(&student_id, student_info);
+            StudentOf::<T>::insert(&account, &student_id);
+            Self::deposit_event(Event::<T>::StudentRegistered(student_id));
+
+            Ok(())
+        }
+
         #[pallet::weight(10_000)]
         pub fn send_enrollment_application(
             origin: OriginFor<T>,
-            application_id: ApplicationId,
+            application_id: Option<ApplicationId>,
             application_info: NewApplicationParam,
         ) -> DispatchResult {
             // send application to get admission in university
+            // if application_id is not passed, chain will assign one
             let applicant =
                 ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let student = StudentOf::<T>::get(&applicant).ok_or(Error::<T>::NoStudent)?;
+            let application_id = application_id
+                .unwrap_or_else(|| <Self as IdGenerator<AccountIdOf<T>>>::generate_id(&applicant));
+            ensure!(
+                !Applications::<T>::contains_key(&application_id),
+                Error::<T>::ApplicationExists
+            );
 
             let NewApplicationParam {
                 university,
@@ -59,6 +142,7 @@ pub mod pallet {
             let application_info = ApplicationInfoFor::<T> {
                 university,
                 applicant,
+                student,
                 application,
             };
 
@@ -106,15 +190,92 @@ pub mod pallet {
             Ok(())
         }
     }
+
+    impl<T: Config> Pallet<T> {
+        /// remove `university_id` from enrollments of `student_id`
+        /// student moves to `status_when_done` once it is not enrolled anywhere
+        pub(crate) fn leave_university(
+            student_id: &StudentId,
+            university_id: &UniversityId,
+            status_when_done: StudentStatus,
+        ) -> DispatchResult {
+            Students::<T>::try_mutate(student_id, |student| -> DispatchResult {
+                let student = student.as_mut().ok_or(Error::<T>::NoStudent)?;
+                student
+                    .enrollments
+                    .retain(|enrolled_in| enrolled_in != university_id);
+                if student.enrollments.is_empty() {
+                    student.status = status_when_done;
+                }
+                Ok(())
+            })
+        }
+    }
+
+    impl<T: Config> IdGenerator<AccountIdOf<T>> for Pallet<T> {
+        const ENTITY: IdEntityKind = IdEntityKind::Application;
+
+        fn next_nonce() -> u64 {
+            IdNonce::<T>::mutate(bump_nonce)
+        }
+    }
 }
 
 impl<T: Config> traits::pallet_provider::StudentProvider for Pallet<T> {
     type ApplicationId = types::student::ApplicationId;
     type ApplicationInfo = types::student::Application<types::AccountIdOf<T>>;
     type StudentId = types::student::StudentId;
+    type StudentInfo = types::student::StudentInfoFor<T>;
+    type FrameConfig = T;
 
-    fn get_student_info() {
-        todo!()
+    fn student_info(student_id: &Self::StudentId) -> Option<Self::StudentInfo> {
+        crate::Pallet::<T>::get_student(student_id)
+    }
+
+    fn student_of(account: &types::AccountIdOf<T>) -> Option<Self::StudentId> {
+        crate::Pallet::<T>::get_student_of(account)
+    }
+
+    fn note_enrollment(
+        student_id: &Self::StudentId,
+        university_id: &types::university::UniversityId,
+    ) -> frame_support::pallet_prelude::DispatchResult {
+        crate::Students::<T>::try_mutate(
+            student_id,
+            |student| -> frame_support::pallet_prelude::DispatchResult {
+                let student = student.as_mut().ok_or(crate::Error::<T>::NoStudent)?;
+                if !student.enrollments.contains(university_id) {
+                    student
+                        .enrollments
+                        .try_push(university_id.clone())
+                        .map_err(|_| crate::Error::<T>::TooManyEnrollments)?;
+                }
+                student.status = types::student::StudentStatus::Active;
+                Ok(())
+            },
+        )
+    }
+
+    fn note_graduation(
+        student_id: &Self::StudentId,
+        university_id: &types::university::UniversityId,
+    ) -> frame_support::pallet_prelude::DispatchResult {
+        crate::Pallet::<T>::leave_university(
+            student_id,
+            university_id,
+            types::student::StudentStatus::Graduated,
+        )
+    }
+
+    fn note_withdrawal(
+        student_id: &Self::StudentId,
+        university_id: &types::university::UniversityId,
+    ) -> frame_support::pallet_prelude::DispatchResult {
+        crate::Pallet::<T>::leave_university(
+            student_id,
+            university_id,
+            types::student::StudentStatus::Registered,
+        )
     }
 
     fn application_info(
@@ -123,4 +284,12 @@ impl<T: Config> traits::pallet_provider::StudentProvider for Pallet<T> {
         crate::Pallet::<T>::get_application(application_id)
             .ok_or(crate::Error::<T>::NoApplication.into())
     }
+
+    fn close_application(
+        application_id: &Self::ApplicationId,
+    ) -> frame_support::pallet_prelude::DispatchResult {
+        crate::Applications::<T>::take(application_id)
+            .map(|_| ())
+            .ok_or(crate::Error::<T>::NoApplication.into())
+    }
 }
@@ -0,0 +1,139 @@
+use crate as pallet_student;
+use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
+    traits::{ConstU16, ConstU32, ConstU64},
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+use traits::pallet_provider::{ProfessorProvider, UniversityProvider};
+use types::{
+    primitives::{StdIpfsLink, UniqId},
+    professor::{NewOfferParam, OfferId, ProfessorId},
+    university::UniversityId,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+pub const STUDENT_ACCOUNT: AccountId = 1;
+pub const OTHER_ACCOUNT: AccountId = 2;
+
+pub const UNIVERSITY: UniqId = UniqId::new([1; 32]);
+pub const OTHER_UNIVERSITY: UniqId = UniqId::new([2; 32]);
+pub const STUDENT: UniqId = UniqId::new([3; 32]);
+pub const APPLICATION: UniqId = UniqId::new([4; 32]);
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        Student: pallet_student,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_student::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ProfessorProvider = MockProfessorProvider;
+    type UniversityProvider = MockUniversityProvider;
+}
+
+pub fn ipfs_link() -> StdIpfsLink {
+    StdIpfsLink::new(Default::default())
+}
+
+/// universities and professors are not used by student tests
+pub struct MockUniversityProvider;
+impl UniversityProvider for MockUniversityProvider {
+    type UniversityId = UniversityId;
+    type UniversityInfo = ();
+    type FrameConfig = Test;
+
+    fn university_info(_: &Self::UniversityId) -> Option<()> {
+        None
+    }
+
+    fn university_admin(_: &Self::UniversityId) -> Option<AccountId> {
+        None
+    }
+
+    fn note_affiliation(_: &Self::UniversityId, _: &ProfessorId) -> DispatchResult {
+        Err(DispatchError::Other("no university"))
+    }
+
+    fn note_disaffiliation(_: &Self::UniversityId, _: &ProfessorId) {}
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register_university_for_benchmark(_: AccountId) -> Self::UniversityId {
+        UNIVERSITY
+    }
+}
+
+pub struct MockProfessorProvider;
+impl ProfessorProvider for MockProfessorProvider {
+    type ProfessorId = ProfessorId;
+    type ProfessorInfo = ();
+    type AffiliationInfo = ();
+    type FrameConfig = Test;
+
+    fn professor_info(_: &Self::ProfessorId) -> Option<()> {
+        None
+    }
+
+    fn affiliation_info(_: &Self::ProfessorId, _: &UniversityId) -> Option<()> {
+        None
+    }
+
+    fn is_affiliated(_: &Self::ProfessorId, _: &UniversityId) -> bool {
+        false
+    }
+
+    fn make_offer(_: &AccountId, _: NewOfferParam<u64>) -> Result<OfferId, DispatchError> {
+        Err(DispatchError::Other("no professor"))
+    }
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap()
+        .into();
+    // events are not deposited on genesis block
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
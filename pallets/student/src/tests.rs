@@ -0,0 +1,214 @@
+use crate::{mock::*, Error, Event, StudentOf};
+use frame_support::{assert_noop, assert_ok};
+use traits::pallet_provider::StudentProvider;
+use types::{
+    primitives::UniqId,
+    student::{MaxEnrollments, NewApplicationParam, NewStudentParam, StudentStatus},
+    university::UniversityId,
+};
+
+fn register_student() {
+    assert_ok!(Student::register_as_student(
+        RuntimeOrigin::signed(STUDENT_ACCOUNT),
+        Some(STUDENT),
+        NewStudentParam {
+            profile: ipfs_link(),
+        },
+    ));
+}
+
+fn enroll(university_id: UniversityId) {
+    assert_ok!(<Student as StudentProvider>::note_enrollment(
+        &STUDENT,
+        &university_id
+    ));
+}
+
+fn status() -> StudentStatus {
+    Student::get_student(&STUDENT).unwrap().status
+}
+
+#[test]
+fn registered_student_have_profile() {
+    new_test_ext().execute_with(|| {
+        register_student();
+
+        let student = <Student as StudentProvider>::student_info(&STUDENT).unwrap();
+        assert_eq!(student.account, STUDENT_ACCOUNT);
+        assert_eq!(student.profile, ipfs_link());
+        assert!(student.enrollments.is_empty());
+        assert_eq!(student.status, StudentStatus::Registered);
+        assert_eq!(
+            <Student as StudentProvider>::student_of(&STUDENT_ACCOUNT),
+            Some(STUDENT)
+        );
+        System::assert_last_event(Event::StudentRegistered(STUDENT).into());
+    });
+}
+
+#[test]
+fn account_can_register_only_one_student() {
+    new_test_ext().execute_with(|| {
+        register_student();
+
+        assert_noop!(
+            Student::register_as_student(
+                RuntimeOrigin::signed(STUDENT_ACCOUNT),
+                Some(UniqId::new([9; 32])),
+                NewStudentParam {
+                    profile: ipfs_link(),
+                },
+            ),
+            Error::<Test>::AlreadyRegistered
+        );
+        assert_noop!(
+            Student::register_as_student(
+                RuntimeOrigin::signed(OTHER_ACCOUNT),
+                Some(STUDENT),
+                NewStudentParam {
+                    profile: ipfs_link(),
+                },
+            ),
+            Error::<Test>::StudentExists
+        );
+    });
+}
+
+#[test]
+fn student_id_is_assigned_by_chain_when_not_given() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Student::register_as_student(
+            RuntimeOrigin::signed(OTHER_ACCOUNT),
+            None,
+            NewStudentParam {
+                profile: ipfs_link(),
+            },
+        ));
+
+        let student_id = StudentOf::<Test>::get(&OTHER_ACCOUNT).unwrap();
+        assert!(Student::get_student(&student_id).is_some());
+        System::assert_last_event(Event::StudentRegistered(student_id).into());
+    });
+}
+
+#[test]
+fn only_registered_student_can_apply() {
+    new_test_ext().execute_with(|| {
+        let application = NewApplicationParam {
+            university: UNIVERSITY,
+            application: ipfs_link(),
+        };
+        assert_noop!(
+            Student::send_enrollment_application(
+                RuntimeOrigin::signed(STUDENT_ACCOUNT),
+                Some(APPLICATION),
+                application.clone(),
+            ),
+            Error::<Test>::NoStudent
+        );
+
+        register_student();
+        assert_ok!(Student::send_enrollment_application(
+            RuntimeOrigin::signed(STUDENT_ACCOUNT),
+            Some(APPLICATION),
+            application,
+        ));
+        let application = <Student as StudentProvider>::application_info(&APPLICATION).unwrap();
+        assert_eq!(application.student, STUDENT);
+        assert_eq!(application.applicant, STUDENT_ACCOUNT);
+    });
+}
+
+#[test]
+fn enrollment_activates_student() {
+    new_test_ext().execute_with(|| {
+        register_student();
+        enroll(UNIVERSITY);
+
+        assert_eq!(status(), StudentStatus::Active);
+        // noting same enrollment again does not duplicate it
+        enroll(UNIVERSITY);
+        assert_eq!(
+            Student::get_student(&STUDENT).unwrap().enrollments.to_vec(),
+            vec![UNIVERSITY]
+        );
+
+        assert_noop!(
+            <Student as StudentProvider>::note_enrollment(&UniqId::new([9; 32]), &UNIVERSITY),
+            Error::<Test>::NoStudent
+        );
+    });
+}
+
+#[test]
+fn enrollments_are_bounded() {
+    new_test_ext().execute_with(|| {
+        register_student();
+        let max_enrollments: u32 = <MaxEnrollments as frame_support::traits::Get<u32>>::get();
+        for seed in 0..max_enrollments {
+            enroll(UniqId::new([seed as u8 + 10; 32]));
+        }
+
+        assert_noop!(
+            <Student as StudentProvider>::note_enrollment(&STUDENT, &UNIVERSITY),
+            Error::<Test>::TooManyEnrollments
+        );
+
+        // leaving an university frees a slot
+        assert_ok!(<Student as StudentProvider>::note_withdrawal(
+            &STUDENT,
+            &UniqId::new([10; 32])
+        ));
+        enroll(UNIVERSITY);
+    });
+}
+
+#[test]
+fn graduation_completes_student_once_not_enrolled_anywhere() {
+    new_test_ext().execute_with(|| {
+        register_student();
+        enroll(UNIVERSITY);
+        enroll(OTHER_UNIVERSITY);
+
+        assert_ok!(<Student as StudentProvider>::note_graduation(
+            &STUDENT,
+            &UNIVERSITY
+        ));
+        assert_eq!(status(), StudentStatus::Active);
+
+        assert_ok!(<Student as StudentProvider>::note_graduation(
+            &STUDENT,
+            &OTHER_UNIVERSITY
+        ));
+        assert_eq!(status(), StudentStatus::Graduated);
+        assert!(Student::get_student(&STUDENT)
+            .unwrap()
+            .enrollments
+            .is_empty());
+    });
+}
+
+#[test]
+fn withdrawal_returns_student_to_registered() {
+    new_test_ext().execute_with(|| {
+        register_student();
+        enroll(UNIVERSITY);
+        enroll(OTHER_UNIVERSITY);
+
+        assert_ok!(<Student as StudentProvider>::note_withdrawal(
+            &STUDENT,
+            &UNIVERSITY
+        ));
+        assert_eq!(status(), StudentStatus::Active);
+
+        assert_ok!(<Student as StudentProvider>::note_withdrawal(
+            &STUDENT,
+            &OTHER_UNIVERSITY
+        ));
+        assert_eq!(status(), StudentStatus::Registered);
+
+        // enrolling again activates the student
+        enroll(UNIVERSITY);
+        assert_eq!(status(), StudentStatus::Active);
+    });
+}
@@ -2,24 +2,43 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
         pallet_prelude::{DispatchResult, *},
+        sp_std::vec::Vec,
         Twox64Concat,
     };
     use frame_system::pallet_prelude::*;
-    use pallet_provider_traits::StudentProvider;
+    use pallet_provider_traits::{ProfessorProvider, StudentProvider, ValidationProvider};
+    use traits::id_generator::{bump_nonce, derive_uniq_id, IdGenerator};
     pub(super) use traits::pallet_provider as pallet_provider_traits;
     pub(super) use types::{
-        primitives::{AccountIdOf, StdIpfsLink as IpfsLink},
-        professor::{NewProfessorParam, ProfessorId},
+        certificate::*,
+        primitives::Page,
+        primitives::{AccountIdOf, IdEntityKind, StdIpfsLink as IpfsLink},
+        professor::{NewContractParam, NewOfferParam, OfferId, ProfessorId, ProfessorInfo},
         student::StudentId,
         university::*,
     };
 
     pub(super) type UniversityInfoFor<T> = University<AccountIdOf<T>>;
+    pub(super) type EnrollmentFor<T> = Enrollment<BlockNumberFor<T>>;
+    pub(super) type CertificateFor<T> = Certificate<BlockNumberFor<T>>;
+    pub(super) type CertificateBatchFor<T> = CertificateBatch<BlockNumberFor<T>>;
+    pub(super) type CertificateHistoryFor<T> = BoundedVec<
+        CertificateTransition<BlockNumberFor<T>>,
+        <T as Config>::MaxCertificateTransitions,
+    >;
     type NewUniversityParamFor<T> = NewUniversityParam<AccountIdOf<T>>;
+    pub(super) type AffiliationFor<T> = types::professor::Affiliation<BlockNumberFor<T>>;
+    type NewContractParamFor<T> = NewContractParam<BlockNumberFor<T>>;
     type ProfessorIdFor<T> = <<T as Config>::ProfessorProvider as pallet_provider_traits::ProfessorProvider>::ProfessorId;
 
     pub(super) type StudentApplicationIdFor<T> =
@@ -33,14 +52,38 @@ pub mod pallet {
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-        type ProfessorProvider: pallet_provider_traits::ProfessorProvider<ProfessorId = ProfessorId>;
+        type ProfessorProvider: pallet_provider_traits::ProfessorProvider<
+            ProfessorId = ProfessorId,
+            ProfessorInfo = ProfessorInfo<AccountIdOf<Self>>,
+            AffiliationInfo = AffiliationFor<Self>,
+            FrameConfig = Self,
+        >;
         type StudentProvider: StudentProvider<
             ApplicationId = types::student::ApplicationId,
             ApplicationInfo = types::student::Application<types::AccountIdOf<Self>>,
+            StudentId = StudentId,
+            StudentInfo = types::student::StudentInfoFor<Self>,
+            FrameConfig = Self,
         >;
         type LectureProvider: pallet_provider_traits::LectureProvider;
         type ExamProvider: pallet_provider_traits::ExamProvider;
         type ScholarshipProvider: pallet_provider_traits::ScholarshipProvider;
+        type ValidationProvider: pallet_provider_traits::ValidationProvider<
+            ValidatorId = types::validator::ValidatorId,
+            UniversityId = UniversityId,
+        >;
+
+        /// Validators at least one of which must have validated a university
+        /// before it can issue certificates. Empty set disables the requirement
+        type TrustedValidators: Get<frame_support::sp_std::vec::Vec<types::validator::ValidatorId>>;
+
+        /// Maximum number of status changes recorded for a single certificate
+        #[pallet::constant]
+        type MaxCertificateTransitions: Get<u32>;
+
+        /// Maximum number of certificates that can be issued in a single batch
+        #[pallet::constant]
+        type MaxCertificatesPerBatch: Get<u32>;
     }
 
     #[pallet::storage]
@@ -48,10 +91,70 @@ pub mod pallet {
     pub type Universities<T> =
         StorageMap<_, Twox64Concat, UniversityId, UniversityInfoFor<T>, OptionQuery>;
 
+    /// Nonce used to derive chain assigned `UniversityId` and `CertificateId`
+    #[pallet::storage]
+    pub type IdNonce<T> = StorageValue<_, u64, ValueQuery>;
+
+    /// Students enrolled in university
     #[pallet::storage]
     #[pallet::getter(fn get_student)]
     pub type Students<T> =
-        StorageDoubleMap<_, Twox64Concat, UniversityId, Twox64Concat, StudentId, ()>;
+        StorageDoubleMap<_, Twox64Concat, UniversityId, Twox64Concat, StudentId, EnrollmentFor<T>>;
+
+    /// Professors currently in the faculty of university
+    /// mirror of affiliations kept by `ProfessorProvider`
+    #[pallet::storage]
+    pub type Faculty<T> =
+        StorageDoubleMap<_, Twox64Concat, UniversityId, Twox64Concat, ProfessorId, ()>;
+
+    /// Certificates issued by universities
+    #[pallet::storage]
+    #[pallet::getter(fn get_certificate)]
+    pub type Certificates<T> = StorageMap<_, Twox64Concat, CertificateId, CertificateFor<T>>;
+
+    /// Batches of certificates issued under a single merkle root
+    #[pallet::storage]
+    #[pallet::getter(fn get_certificate_batch)]
+    pub type CertificateBatches<T> =
+        StorageMap<_, Twox64Concat, CertificateBatchId, CertificateBatchFor<T>>;
+
+    /// Status of certificates in a batch that have been moved out of `Active`
+    /// certificates are identified by the hash of their leaf
+    #[pallet::storage]
+    #[pallet::getter(fn get_batched_certificate_status)]
+    pub type BatchedCertificateStatus<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        CertificateBatchId,
+        Twox64Concat,
+        types::merkle::MerkleHash,
+        CertificateStatus,
+    >;
+
+    /// Every status change of a certificate in order they happened
+    #[pallet::storage]
+    #[pallet::getter(fn get_certificate_history)]
+    pub type CertificateHistory<T> =
+        StorageMap<_, Twox64Concat, CertificateId, CertificateHistoryFor<T>, ValueQuery>;
+
+    /// Every status change of a batch as a whole in order they happened
+    #[pallet::storage]
+    #[pallet::getter(fn get_certificate_batch_history)]
+    pub type CertificateBatchHistory<T> =
+        StorageMap<_, Twox64Concat, CertificateBatchId, CertificateHistoryFor<T>, ValueQuery>;
+
+    /// Every status change of a single certificate in a batch in order they happened
+    #[pallet::storage]
+    #[pallet::getter(fn get_batched_certificate_history)]
+    pub type BatchedCertificateHistory<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        CertificateBatchId,
+        Twox64Concat,
+        types::merkle::MerkleHash,
+        CertificateHistoryFor<T>,
+        ValueQuery,
+    >;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -60,6 +163,57 @@ pub mod pallet {
         ApplicationAccepted(StudentApplicationIdFor<T>),
         /// UniversityRegistered
         NewUniversity(UniversityId),
+        /// Student have been enrolled in university
+        StudentEnrolled(UniversityId, StudentId, EnrollmentSource),
+        /// Student have completed the studies in university
+        StudentGraduated(UniversityId, StudentId),
+        /// Student have withdrawn it's enrollment from university
+        StudentWithdrawn(UniversityId, StudentId),
+        /// University have proposed an employment contract to professor
+        /// professor joins the university by accepting the offer
+        ContractProposed {
+            university: UniversityId,
+            professor: ProfessorId,
+            offer: OfferId,
+        },
+        /// Certificate have been issued to student
+        CertificateIssued {
+            certificate_id: CertificateId,
+            university: UniversityId,
+            student: StudentId,
+        },
+        /// Batch of certificates have been issued
+        CertificateBatchIssued {
+            batch_id: CertificateBatchId,
+            university: UniversityId,
+            root: types::merkle::MerkleHash,
+            count: u32,
+        },
+        /// Certificate have been revoked
+        CertificateRevoked(CertificateId, CertificateReason),
+        /// Certificate have been suspended
+        CertificateSuspended(CertificateId, CertificateReason),
+        /// Suspended certificate is active again
+        CertificateReinstated(CertificateId, CertificateReason),
+        /// Certificate have been replaced by new one
+        CertificateReissued {
+            certificate_id: CertificateId,
+            reissued_as: CertificateId,
+            reason: CertificateReason,
+        },
+        /// Status of every certificate in the batch have been changed
+        CertificateBatchStatusChanged {
+            batch_id: CertificateBatchId,
+            status: CertificateStatus,
+            reason: CertificateReason,
+        },
+        /// Status of a single certificate in the batch have been changed
+        BatchedCertificateStatusChanged {
+            batch_id: CertificateBatchId,
+            leaf: types::merkle::MerkleHash,
+            status: CertificateStatus,
+            reason: CertificateReason,
+        },
     }
 
     #[pallet::error]
@@ -72,6 +226,32 @@ pub mod pallet {
         UniversityExists,
         /// Professor does not exists
         NoProfessor,
+        /// Professor is already affiliated with this university
+        AlreadyAffiliated,
+        /// Student is already enrolled in this university
+        AlreadyEnrolled,
+        /// No student with given id exists
+        NoStudent,
+        /// Student is not enrolled in this university
+        NotEnrolled,
+        /// Student have already graduated from this university
+        AlreadyGraduated,
+        /// Certificate with this id already exists
+        CertificateExists,
+        /// No certificate with given id exists
+        NoCertificate,
+        /// Certificate cannot move to requested status from it's current status
+        InvalidCertificateStatus,
+        /// Certificate have reached the maximum number of status changes
+        TooManyTransitions,
+        /// Batch does not contain any certificate
+        EmptyBatch,
+        /// Batch with this id already exists
+        BatchExists,
+        /// No batch with given id exists
+        NoBatch,
+        /// University is not validated by any of the `TrustedValidators`
+        UniversityNotValidated,
     }
 
     #[pallet::call]
@@ -79,7 +259,7 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn register_university(
             origin: OriginFor<T>,
-            university_id: UniversityId,
+            university_id: Option<UniversityId>,
             info: NewUniversityParamFor<T>,
         ) -> DispatchResult {
             // regitser this university with given info
@@ -89,7 +269,11 @@ pub mod pallet {
             //
             //  }
 
+            // if no university_id is passed, chain will assign one
+            // and it can be known from the emitted event
             let signer = ensure_signed(origin)?;
+            let university_id = university_id
+                .unwrap_or_else(|| <Self as IdGenerator<AccountIdOf<T>>>::generate_id(&signer));
             Self::verify_new_id(&university_id)?;
 
             let NewUniversityParam {
@@ -114,7 +298,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             university_id: UniversityId,
             professor_id: ProfessorIdFor<T>,
-            info: NewProfessorParam,
+            terms: NewContractParamFor<T>,
         ) -> DispatchResult {
             // sign a contract by university to add the already registered professor to this
             // university note: professor_id is independent to university_id. i.e professor can
@@ -122,19 +306,41 @@ pub mod pallet {
             //
             // also this addition has also to be signed by professor to validate the addition
             // contract the unsigned contract has the death period of X block number i.e after X
-            // block this contract will be invilated and shall be re-issued if required. so
-            // professor is required to sign the contract beforehand that also depend the fee on
-            // value of X block number. the longer the temporary contract period larger the fee for
-            // university
+            // block this contract will be invilated and shall be re-issued if required.
             //
-            // add into map like:
-            // UniversityProfessors: [university_id, professor_id] -> faculty_info / other info
-            // also in ProfessorProvider it makes sense to have another storage synced to this
-            // Professors: [professor_id] -> Info { university: vec![university_id] }
+            // contract is made to professor as an offer of `ProfessorProvider`
+            // which takes care of deposit, expiry, and professor's signature.
+            // once professor accepts the offer the affiliation is mirrored in `Faculty`
 
-            Self::ensure_university_admin(origin, &university_id)?;
-            <<T as Config>::ProfessorProvider as pallet_provider_traits::ProfessorProvider>
-                ::professor_info(&professor_id).ok_or(Error::<T>::NoProfessor)?;
+            let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            Self::verify_university_admin(signer.clone(), &university_id)?;
+            T::ProfessorProvider::professor_info(&professor_id).ok_or(Error::<T>::NoProfessor)?;
+            ensure!(
+                !T::ProfessorProvider::is_affiliated(&professor_id, &university_id),
+                Error::<T>::AlreadyAffiliated
+            );
+
+            let NewContractParam {
+                role,
+                faculty,
+                contract,
+                validity,
+            } = terms;
+            let offer = NewOfferParam {
+                professor: professor_id,
+                university: university_id,
+                contract_file: contract,
+                role,
+                faculty,
+                validity,
+            };
+            let offer_id = T::ProfessorProvider::make_offer(&signer, offer)?;
+
+            Self::deposit_event(Event::<T>::ContractProposed {
+                university: university_id,
+                professor: professor_id,
+                offer: offer_id,
+            });
 
             Ok(())
         }
@@ -152,19 +358,77 @@ pub mod pallet {
             let application_info = T::StudentProvider::application_info(&application_id)?;
             Self::verify_university_admin(signer, &application_info.university)?;
 
-            // - Do something to signify acceptance of enrollment application
-            // - delete the application
+            // application is removed so it cannot be accepted again
+            T::StudentProvider::close_application(&application_id)?;
+            Self::do_enroll(
+                &application_info.university,
+                &application_info.student,
+                EnrollmentSource::Application(application_id.clone()),
+                None,
+            )?;
 
             Self::deposit_event(Event::<T>::ApplicationAccepted(application_id));
 
             Ok(())
         }
 
+        #[pallet::weight(10_000)]
+        pub fn graduate_student(
+            origin: OriginFor<T>,
+            university_id: UniversityId,
+            student_id: StudentId,
+        ) -> DispatchResult {
+            // enrollment record is kept so certificates can still refer to it
+            Self::ensure_university_admin(origin, &university_id)?;
+            Students::<T>::try_mutate(
+                &university_id,
+                &student_id,
+                |enrollment| -> DispatchResult {
+                    let enrollment = enrollment.as_mut().ok_or(Error::<T>::NotEnrolled)?;
+                    ensure!(
+                        enrollment.graduated_on.is_none(),
+                        Error::<T>::AlreadyGraduated
+                    );
+                    enrollment.graduated_on = Some(frame_system::Pallet::<T>::block_number());
+                    T::StudentProvider::note_graduation(&student_id, &university_id)
+                },
+            )?;
+
+            Self::deposit_event(Event::<T>::StudentGraduated(university_id, student_id));
+
+            Ok(())
+        }
+
+        #[pallet::weight(10_000)]
+        pub fn withdraw_enrollment(
+            origin: OriginFor<T>,
+            university_id: UniversityId,
+        ) -> DispatchResult {
+            // student leaves the university before graduating
+            let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let student_id =
+                T::StudentProvider::student_of(&signer).ok_or(Error::<T>::NoStudent)?;
+            let enrollment =
+                Self::get_student(&university_id, &student_id).ok_or(Error::<T>::NotEnrolled)?;
+            ensure!(
+                enrollment.graduated_on.is_none(),
+                Error::<T>::AlreadyGraduated
+            );
+
+            T::StudentProvider::note_withdrawal(&student_id, &university_id)?;
+            Students::<T>::remove(&university_id, &student_id);
+            Self::deposit_event(Event::<T>::StudentWithdrawn(university_id, student_id));
+
+            Ok(())
+        }
+
         #[pallet::weight(10_000)]
         pub fn issue_certificate(
             origin: OriginFor<T>,
+            university_id: UniversityId,
+            certificate_id: Option<CertificateId>,
             student_id: StudentId,
-            certificate: IpfsLink,
+            certificate: NewCertificateParam,
         ) -> DispatchResult {
             // issue a certificate to student student_id
             // cerificate is the ipfs link to certificate docoument
@@ -174,8 +438,277 @@ pub mod pallet {
             // approve it and then pass these details to make a certofocate accorsing to the
             // university design
 
+            // only university where student is enrolled can issue the certificate
+            // if certificate_id is not passed, chain will assign one
+            let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            Self::verify_university_admin(signer.clone(), &university_id)?;
+            Self::do_issue_certificate(
+                &signer,
+                university_id,
+                certificate_id,
+                student_id,
+                certificate,
+                None,
+            )?;
+
+            Ok(())
+        }
+
+        /// issue many certificates at once by committing to their merkle root
+        /// holder of each certificate can prove it's inclusion with a merkle proof
+        #[pallet::weight(10_000)]
+        pub fn issue_certificate_batch(
+            origin: OriginFor<T>,
+            university_id: UniversityId,
+            batch_id: Option<CertificateBatchId>,
+            leaves: BoundedVec<CertificateLeaf, T::MaxCertificatesPerBatch>,
+            manifest: IpfsLink,
+        ) -> DispatchResult {
+            // if batch_id is not passed, chain will assign one
+            // only leaves are passed so that chain can check every holder is enrolled
+            // in the university, and only their merkle root is stored
+            let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            Self::verify_university_admin(signer.clone(), &university_id)?;
+            Self::ensure_trusted_university(&university_id)?;
+            for leaf in leaves.iter() {
+                ensure!(
+                    Students::<T>::contains_key(&university_id, &leaf.student),
+                    Error::<T>::NotEnrolled
+                );
+            }
+
+            let hashes = leaves.iter().map(CertificateLeaf::hash).collect::<Vec<_>>();
+            let root = types::merkle::merkle_root(&hashes).ok_or(Error::<T>::EmptyBatch)?;
+            let count = hashes.len() as u32;
+
+            let batch_id = batch_id.unwrap_or_else(|| {
+                derive_uniq_id(IdEntityKind::Certificate, Self::next_nonce(), &signer)
+            });
+            ensure!(
+                !CertificateBatches::<T>::contains_key(&batch_id),
+                Error::<T>::BatchExists
+            );
+
+            let batch = CertificateBatchFor::<T> {
+                university: university_id.clone(),
+                root,
+                count,
+                issued_on: frame_system::Pallet::<T>::block_number(),
+                manifest,
+                status: CertificateStatus::Active,
+            };
+
+            CertificateBatches::<T>::insert(&batch_id, batch);
+            Self::deposit_event(Event::<T>::CertificateBatchIssued {
+                batch_id,
+                university: university_id,
+                root,
+                count,
+            });
+
             Ok(())
         }
+
+        /// change status of every certificate in the batch i.e when whole batch was issued by
+        /// mistake. batch as a whole cannot be reissued
+        #[pallet::weight(10_000)]
+        pub fn transit_certificate_batch(
+            origin: OriginFor<T>,
+            batch_id: CertificateBatchId,
+            status: CertificateStatus,
+            reason: CertificateReason,
+            evidence: IpfsLink,
+        ) -> DispatchResult {
+            let batch = Self::get_certificate_batch(&batch_id).ok_or(Error::<T>::NoBatch)?;
+            Self::ensure_university_admin(origin, &batch.university)?;
+            ensure!(
+                !matches!(status, CertificateStatus::Reissued(..))
+                    && batch.status.can_move_to(&status),
+                Error::<T>::InvalidCertificateStatus
+            );
+
+            let transition = Self::new_transition(status.clone(), reason.clone(), evidence);
+            CertificateBatchHistory::<T>::try_append(&batch_id, transition)
+                .map_err(|_| Error::<T>::TooManyTransitions)?;
+            CertificateBatches::<T>::mutate(&batch_id, |batch| {
+                if let Some(batch) = batch {
+                    batch.status = status.clone();
+                }
+            });
+            Self::deposit_event(Event::<T>::CertificateBatchStatusChanged {
+                batch_id,
+                status,
+                reason,
+            });
+            Ok(())
+        }
+
+        /// change status of a single certificate in the batch
+        /// certificate can only be reissued as an individual certificate to the same student
+        #[pallet::weight(10_000)]
+        pub fn transit_batched_certificate(
+            origin: OriginFor<T>,
+            batch_id: CertificateBatchId,
+            leaf: CertificateLeaf,
+            status: CertificateStatus,
+            reason: CertificateReason,
+            evidence: IpfsLink,
+        ) -> DispatchResult {
+            let batch = Self::get_certificate_batch(&batch_id).ok_or(Error::<T>::NoBatch)?;
+            Self::ensure_university_admin(origin, &batch.university)?;
+            // once whole batch is invalidated it's certificates cannot be changed individually
+            ensure!(
+                batch.status == CertificateStatus::Active,
+                Error::<T>::InvalidCertificateStatus
+            );
+
+            let leaf_hash = leaf.hash();
+            let current = Self::get_batched_certificate_status(&batch_id, &leaf_hash)
+                .unwrap_or(CertificateStatus::Active);
+            ensure!(
+                current.can_move_to(&status),
+                Error::<T>::InvalidCertificateStatus
+            );
+            if let CertificateStatus::Reissued(reissued_as) = &status {
+                let reissued =
+                    Self::get_certificate(reissued_as).ok_or(Error::<T>::NoCertificate)?;
+                ensure!(
+                    reissued.university == batch.university && reissued.student == leaf.student,
+                    Error::<T>::InvalidCertificateStatus
+                );
+            }
+
+            let transition = Self::new_transition(status.clone(), reason.clone(), evidence);
+            BatchedCertificateHistory::<T>::try_append(&batch_id, &leaf_hash, transition)
+                .map_err(|_| Error::<T>::TooManyTransitions)?;
+            if status == CertificateStatus::Active {
+                BatchedCertificateStatus::<T>::remove(&batch_id, &leaf_hash);
+            } else {
+                BatchedCertificateStatus::<T>::insert(&batch_id, &leaf_hash, &status);
+            }
+            Self::deposit_event(Event::<T>::BatchedCertificateStatusChanged {
+                batch_id,
+                leaf: leaf_hash,
+                status,
+                reason,
+            });
+            Ok(())
+        }
+
+        /// permanently invalidate the certificate
+        #[pallet::weight(10_000)]
+        pub fn revoke_certificate(
+            origin: OriginFor<T>,
+            certificate_id: CertificateId,
+            reason: CertificateReason,
+            evidence: IpfsLink,
+        ) -> DispatchResult {
+            Self::ensure_certificate_issuer(origin, &certificate_id)?;
+            Self::transit_certificate(
+                &certificate_id,
+                CertificateStatus::Revoked,
+                reason.clone(),
+                evidence,
+            )?;
+
+            Self::deposit_event(Event::<T>::CertificateRevoked(certificate_id, reason));
+            Ok(())
+        }
+
+        /// temporarily invalidate the certificate i.e while it is under investigation
+        #[pallet::weight(10_000)]
+        pub fn suspend_certificate(
+            origin: OriginFor<T>,
+            certificate_id: CertificateId,
+            reason: CertificateReason,
+            evidence: IpfsLink,
+        ) -> DispatchResult {
+            let certificate = Self::ensure_certificate_issuer(origin, &certificate_id)?;
+            ensure!(
+                certificate.status == CertificateStatus::Active,
+                Error::<T>::InvalidCertificateStatus
+            );
+            Self::transit_certificate(
+                &certificate_id,
+                CertificateStatus::Suspended,
+                reason.clone(),
+                evidence,
+            )?;
+
+            Self::deposit_event(Event::<T>::CertificateSuspended(certificate_id, reason));
+            Ok(())
+        }
+
+        /// make the suspended certificate valid again
+        #[pallet::weight(10_000)]
+        pub fn reinstate_certificate(
+            origin: OriginFor<T>,
+            certificate_id: CertificateId,
+            reason: CertificateReason,
+            evidence: IpfsLink,
+        ) -> DispatchResult {
+            let certificate = Self::ensure_certificate_issuer(origin, &certificate_id)?;
+            ensure!(
+                certificate.status == CertificateStatus::Suspended,
+                Error::<T>::InvalidCertificateStatus
+            );
+            Self::transit_certificate(
+                &certificate_id,
+                CertificateStatus::Active,
+                reason.clone(),
+                evidence,
+            )?;
+
+            Self::deposit_event(Event::<T>::CertificateReinstated(certificate_id, reason));
+            Ok(())
+        }
+
+        /// replace the certificate with a new one i.e after holder changed their name
+        /// old certificate points to the new one
+        #[pallet::weight(10_000)]
+        pub fn reissue_certificate(
+            origin: OriginFor<T>,
+            certificate_id: CertificateId,
+            new_certificate_id: Option<CertificateId>,
+            certificate: NewCertificateParam,
+            reason: CertificateReason,
+            evidence: IpfsLink,
+        ) -> DispatchResult {
+            let signer = ensure_signed(origin).map_err(|_| Error::<T>::InsufficientPermission)?;
+            let old_certificate =
+                Self::get_certificate(&certificate_id).ok_or(Error::<T>::NoCertificate)?;
+            Self::verify_university_admin(signer.clone(), &old_certificate.university)?;
+
+            let reissued_as = Self::do_issue_certificate(
+                &signer,
+                old_certificate.university,
+                new_certificate_id,
+                old_certificate.student,
+                certificate,
+                Some(certificate_id.clone()),
+            )?;
+            Self::transit_certificate(
+                &certificate_id,
+                CertificateStatus::Reissued(reissued_as.clone()),
+                reason.clone(),
+                evidence,
+            )?;
+
+            Self::deposit_event(Event::<T>::CertificateReissued {
+                certificate_id,
+                reissued_as,
+                reason,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> IdGenerator<AccountIdOf<T>> for Pallet<T> {
+        const ENTITY: IdEntityKind = IdEntityKind::University;
+
+        fn next_nonce() -> u64 {
+            IdNonce::<T>::mutate(bump_nonce)
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -187,6 +720,232 @@ pub mod pallet {
             Ok(())
         }
 
+        /// issue a certificate of `university_id` to `student_id`
+        /// caller must have verified that `issuer` is admin of `university_id`
+        pub fn do_issue_certificate(
+            issuer: &AccountIdOf<T>,
+            university_id: UniversityId,
+            certificate_id: Option<CertificateId>,
+            student_id: StudentId,
+            certificate: NewCertificateParam,
+            reissue_of: Option<CertificateId>,
+        ) -> Result<CertificateId, DispatchError> {
+            Self::ensure_trusted_university(&university_id)?;
+            let enrollment =
+                Self::get_student(&university_id, &student_id).ok_or(Error::<T>::NotEnrolled)?;
+
+            let certificate_id = certificate_id.unwrap_or_else(|| {
+                derive_uniq_id(IdEntityKind::Certificate, Self::next_nonce(), issuer)
+            });
+            ensure!(
+                !Certificates::<T>::contains_key(&certificate_id),
+                Error::<T>::CertificateExists
+            );
+
+            let NewCertificateParam {
+                programme,
+                document,
+                document_hash,
+            } = certificate;
+            let certificate = CertificateFor::<T> {
+                university: university_id.clone(),
+                student: student_id.clone(),
+                programme: programme.or(enrollment.programme),
+                issued_on: frame_system::Pallet::<T>::block_number(),
+                document,
+                document_hash,
+                status: CertificateStatus::Active,
+                reissue_of,
+            };
+
+            Certificates::<T>::insert(&certificate_id, certificate);
+            Self::deposit_event(Event::<T>::CertificateIssued {
+                certificate_id: certificate_id.clone(),
+                university: university_id,
+                student: student_id,
+            });
+
+            Ok(certificate_id)
+        }
+
+        /// ensure university is validated by one of the `TrustedValidators`
+        /// always passes if no validator is configured
+        pub fn ensure_trusted_university(university_id: &UniversityId) -> DispatchResult {
+            let trusted = T::TrustedValidators::get();
+            ensure!(
+                trusted.is_empty()
+                    || T::ValidationProvider::is_university_trusted_by(university_id, &trusted),
+                Error::<T>::UniversityNotValidated
+            );
+            Ok(())
+        }
+
+        /// verify the certificate against presented document hash
+        /// and optionally check whether issuer is validated by `validator`
+        pub fn certificate_verification(
+            certificate_id: &CertificateId,
+            document_hash: &DocumentHash,
+            validator: Option<types::validator::ValidatorId>,
+        ) -> Option<CertificateVerification<BlockNumberFor<T>>> {
+            let certificate = Self::get_certificate(certificate_id)?;
+            let issuer_validated = validator.map(|validator| {
+                T::ValidationProvider::is_verified_university(&validator, &certificate.university)
+            });
+
+            Some(CertificateVerification {
+                document_matches: &certificate.document_hash == document_hash,
+                issuer: certificate.university,
+                holder: certificate.student,
+                programme: certificate.programme,
+                issued_on: certificate.issued_on,
+                status: certificate.status,
+                issuer_validated,
+            })
+        }
+
+        /// verify a certificate included in batch `batch_id` with merkle `proof`
+        ///
+        /// certificate only matches if the proof is valid against stored root
+        /// and holder is enrolled in the issuing university
+        pub fn batched_certificate_verification(
+            batch_id: &CertificateBatchId,
+            leaf: &CertificateLeaf,
+            proof: &[types::merkle::MerkleHash],
+            validator: Option<types::validator::ValidatorId>,
+        ) -> Option<CertificateVerification<BlockNumberFor<T>>> {
+            let batch = Self::get_certificate_batch(batch_id)?;
+            let leaf_hash = leaf.hash();
+            let included = types::merkle::verify_proof(&batch.root, &leaf_hash, proof);
+            let enrolled = Students::<T>::contains_key(&batch.university, &leaf.student);
+            let status = match batch.status {
+                CertificateStatus::Active => {
+                    Self::get_batched_certificate_status(batch_id, &leaf_hash)
+                        .unwrap_or(CertificateStatus::Active)
+                }
+                status => status,
+            };
+            let issuer_validated = validator.map(|validator| {
+                T::ValidationProvider::is_verified_university(&validator, &batch.university)
+            });
+
+            Some(CertificateVerification {
+                document_matches: included && enrolled,
+                issuer: batch.university,
+                holder: leaf.student.clone(),
+                programme: leaf.programme.clone(),
+                issued_on: batch.issued_on,
+                status,
+                issuer_validated,
+            })
+        }
+
+        /// ensure origin is admin of the university that issued the certificate
+        pub fn ensure_certificate_issuer(
+            origin: OriginFor<T>,
+            certificate_id: &CertificateId,
+        ) -> Result<CertificateFor<T>, DispatchError> {
+            let certificate =
+                Self::get_certificate(certificate_id).ok_or(Error::<T>::NoCertificate)?;
+            Self::ensure_university_admin(origin, &certificate.university)?;
+            Ok(certificate)
+        }
+
+        /// move certificate to `status` and record the transition in it's history
+        /// revoked or reissued certificate cannot be moved anymore
+        pub fn transit_certificate(
+            certificate_id: &CertificateId,
+            status: CertificateStatus,
+            reason: CertificateReason,
+            evidence: IpfsLink,
+        ) -> DispatchResult {
+            Certificates::<T>::try_mutate(certificate_id, |certificate| -> DispatchResult {
+                let certificate = certificate.as_mut().ok_or(Error::<T>::NoCertificate)?;
+                ensure!(
+                    !certificate.status.is_final(),
+                    Error::<T>::InvalidCertificateStatus
+                );
+
+                let transition = Self::new_transition(status.clone(), reason, evidence);
+                CertificateHistory::<T>::try_append(certificate_id, transition)
+                    .map_err(|_| Error::<T>::TooManyTransitions)?;
+                certificate.status = status;
+
+                Ok(())
+            })
+        }
+
+        /// transition to `status` happening in current block
+        fn new_transition(
+            status: CertificateStatus,
+            reason: CertificateReason,
+            evidence: IpfsLink,
+        ) -> CertificateTransition<BlockNumberFor<T>> {
+            CertificateTransition {
+                status,
+                reason,
+                evidence,
+                at: frame_system::Pallet::<T>::block_number(),
+            }
+        }
+
+        /// single path through which every student gets enrolled
+        pub fn do_enroll(
+            university_id: &UniversityId,
+            student_id: &StudentId,
+            source: EnrollmentSource,
+            programme: Option<ProgrammeId>,
+        ) -> DispatchResult {
+            ensure!(
+                Universities::<T>::contains_key(university_id),
+                Error::<T>::NoUniversity
+            );
+            ensure!(
+                !Students::<T>::contains_key(university_id, student_id),
+                Error::<T>::AlreadyEnrolled
+            );
+            // only registered students can be enrolled
+            T::StudentProvider::note_enrollment(student_id, university_id)?;
+
+            let enrollment = EnrollmentFor::<T> {
+                enrolled_on: frame_system::Pallet::<T>::block_number(),
+                source: source.clone(),
+                programme,
+                graduated_on: None,
+            };
+            Students::<T>::insert(university_id, student_id, enrollment);
+            Self::deposit_event(Event::<T>::StudentEnrolled(
+                university_id.clone(),
+                student_id.clone(),
+                source,
+            ));
+
+            Ok(())
+        }
+
+        /// page of professors currently in faculty of university
+        pub fn faculty_page(
+            university_id: &UniversityId,
+            start: Option<ProfessorId>,
+            limit: u32,
+        ) -> Page<ProfessorId, AffiliationFor<T>> {
+            let Page { items, next } = traits::pagination::double_map_page::<Faculty<T>, _, _, _>(
+                university_id.clone(),
+                start,
+                limit,
+            );
+
+            Page {
+                items: items
+                    .into_iter()
+                    .filter_map(|(professor_id, ())| {
+                        T::ProfessorProvider::affiliation_info(&professor_id, university_id)
+                            .map(|affiliation| (professor_id, affiliation))
+                    })
+                    .collect(),
+                next,
+            }
+        }
+
         pub fn ensure_university_admin(
             origin: OriginFor<T>,
             university_id: &UniversityId,
@@ -226,4 +985,65 @@ impl<T: Config> pallet_provider_traits::UniversityProvider for Pallet<T> {
     ) -> Option<types::AccountIdOf<Self::FrameConfig>> {
         Self::university_info(university_id).map(|info| info.admin)
     }
+
+    fn note_affiliation(
+        university_id: &Self::UniversityId,
+        professor_id: &crate::ProfessorId,
+    ) -> frame_support::dispatch::DispatchResult {
+        frame_support::ensure!(
+            crate::Universities::<T>::contains_key(university_id),
+            crate::Error::<T>::NoUniversity
+        );
+        crate::Faculty::<T>::insert(university_id, professor_id, ());
+        Ok(())
+    }
+
+    fn note_disaffiliation(university_id: &Self::UniversityId, professor_id: &crate::ProfessorId) {
+        crate::Faculty::<T>::remove(university_id, professor_id);
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register_university_for_benchmark(
+        admin: types::AccountIdOf<Self::FrameConfig>,
+    ) -> Self::UniversityId {
+        use traits::id_generator::IdGenerator;
+
+        let university_id = <Self as IdGenerator<_>>::generate_id(&admin);
+        let university = crate::UniversityInfoFor::<T> {
+            admin,
+            permanent_info: crate::IpfsLink::new(Default::default()),
+        };
+        crate::Universities::<T>::insert(&university_id, university);
+
+        university_id
+    }
+}
+
+impl<T: Config> pallet_provider_traits::EnrollmentProvider for Pallet<T> {
+    type UniversityId = crate::UniversityId;
+    type StudentId = crate::StudentId;
+    type EnrollmentInfo = crate::EnrollmentFor<T>;
+
+    fn enrollment_info(
+        university_id: &Self::UniversityId,
+        student_id: &Self::StudentId,
+    ) -> Option<Self::EnrollmentInfo> {
+        crate::Pallet::<T>::get_student(university_id, student_id)
+    }
+
+    /// graduates are no longer enrolled
+    fn is_enrolled(university_id: &Self::UniversityId, student_id: &Self::StudentId) -> bool {
+        Self::enrollment_info(university_id, student_id)
+            .map(|enrollment| enrollment.graduated_on.is_none())
+            .unwrap_or(false)
+    }
+
+    fn enroll(
+        university_id: &Self::UniversityId,
+        student_id: &Self::StudentId,
+        source: crate::EnrollmentSource,
+        programme: Option<crate::ProgrammeId>,
+    ) -> frame_support::dispatch::DispatchResult {
+        crate::Pallet::<T>::do_enroll(university_id, student_id, source, programme)
+    }
 }
@@ -0,0 +1,218 @@
+use crate as pallet_university;
+use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
+    parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64},
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+use std::cell::RefCell;
+use traits::pallet_provider::{
+    ExamProvider, LectureProvider, ProfessorProvider, ScholarshipProvider, ValidationProvider,
+};
+use types::{
+    primitives::{StdIpfsLink, UniqId},
+    professor::{NewOfferParam, OfferId, ProfessorId, ProfessorInfo},
+    university::UniversityId,
+    validator::{ValidatorId, ValidatorTier},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+
+pub const UNIVERSITY_ADMIN: AccountId = 1;
+pub const STUDENT_ACCOUNT: AccountId = 2;
+pub const OTHER_ACCOUNT: AccountId = 3;
+pub const PROFESSOR_ACCOUNT: AccountId = 4;
+
+pub const UNIVERSITY: UniqId = UniqId::new([1; 32]);
+pub const STUDENT: UniqId = UniqId::new([2; 32]);
+pub const APPLICATION: UniqId = UniqId::new([3; 32]);
+pub const PROFESSOR: UniqId = UniqId::new([6; 32]);
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        University: pallet_university,
+        Student: pallet_student,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+    pub TrustedValidators: Vec<ValidatorId> = Vec::new();
+}
+
+impl pallet_university::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ProfessorProvider = MockProfessorProvider;
+    type StudentProvider = Student;
+    type LectureProvider = MockAcademicProvider;
+    type ExamProvider = MockAcademicProvider;
+    type ScholarshipProvider = MockAcademicProvider;
+    type ValidationProvider = MockValidationProvider;
+    type TrustedValidators = TrustedValidators;
+    type MaxCertificateTransitions = ConstU32<4>;
+    type MaxCertificatesPerBatch = ConstU32<4>;
+}
+
+impl pallet_student::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ProfessorProvider = MockProfessorProvider;
+    type UniversityProvider = University;
+}
+
+pub fn ipfs_link() -> StdIpfsLink {
+    StdIpfsLink::new(Default::default())
+}
+
+thread_local! {
+    static OFFERS: RefCell<Vec<(AccountId, NewOfferParam<u64>)>> = RefCell::new(Vec::new());
+}
+
+/// offers made through `MockProfessorProvider` along with the account that made them
+pub fn offers_made() -> Vec<(AccountId, NewOfferParam<u64>)> {
+    OFFERS.with(|offers| offers.borrow().clone())
+}
+
+/// id assigned to the offer made at `index`
+pub fn offer_id_of(index: usize) -> OfferId {
+    UniqId::new([index as u8; 32])
+}
+
+/// offers are kept in memory of the test thread
+pub struct MockProfessorProvider;
+impl ProfessorProvider for MockProfessorProvider {
+    type ProfessorId = ProfessorId;
+    type ProfessorInfo = ProfessorInfo<AccountId>;
+    type AffiliationInfo = crate::AffiliationFor<Test>;
+    type FrameConfig = Test;
+
+    /// every professor is registered by `PROFESSOR_ACCOUNT`
+    fn professor_info(_: &Self::ProfessorId) -> Option<Self::ProfessorInfo> {
+        Some(ProfessorInfo {
+            professor: PROFESSOR_ACCOUNT,
+            info: ipfs_link(),
+        })
+    }
+
+    fn affiliation_info(_: &Self::ProfessorId, _: &UniversityId) -> Option<Self::AffiliationInfo> {
+        None
+    }
+
+    fn is_affiliated(_: &Self::ProfessorId, _: &UniversityId) -> bool {
+        false
+    }
+
+    fn make_offer(who: &AccountId, offer: NewOfferParam<u64>) -> Result<OfferId, DispatchError> {
+        OFFERS.with(|offers| {
+            let mut offers = offers.borrow_mut();
+            offers.push((*who, offer));
+            Ok(offer_id_of(offers.len() - 1))
+        })
+    }
+}
+
+/// lectures, exams and scholarships are not used by university tests
+pub struct MockAcademicProvider;
+impl LectureProvider for MockAcademicProvider {
+    type LectureId = UniqId;
+    type LectureInfo = ();
+
+    fn lecture_info(_: &Self::LectureId) -> Option<()> {
+        None
+    }
+}
+
+impl ExamProvider for MockAcademicProvider {
+    type ExamId = UniqId;
+    type ExamInfo = ();
+
+    fn exam_info(_: &Self::ExamId) -> Option<()> {
+        None
+    }
+}
+
+impl ScholarshipProvider for MockAcademicProvider {
+    type ScholarshipId = UniqId;
+    type ScholarshipInfo = ();
+
+    fn scholarship_info(_: &Self::ScholarshipId) -> Option<()> {
+        None
+    }
+}
+
+pub struct MockValidationProvider;
+impl ValidationProvider for MockValidationProvider {
+    type ValidatorId = ValidatorId;
+    type UniversityId = UniversityId;
+    type ProfessorId = ProfessorId;
+    type ValidatedUniversityInfo = ();
+    type ValidatedProfessorInfo = ();
+
+    fn validated_university_info(_: &ValidatorId, _: &UniversityId) -> Option<()> {
+        None
+    }
+
+    fn validated_professor_info(_: &ValidatorId, _: &ProfessorId) -> Option<()> {
+        None
+    }
+
+    fn validator_tier(_: &ValidatorId) -> Option<ValidatorTier> {
+        None
+    }
+
+    fn university_validators(_: &UniversityId) -> Vec<ValidatorId> {
+        Vec::new()
+    }
+
+    fn professor_validators(_: &ProfessorId) -> Vec<ValidatorId> {
+        Vec::new()
+    }
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap()
+        .into();
+    // events are not deposited on genesis block
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,566 @@
+use crate::{mock::*, BatchedCertificateStatus, Error, Event, Students};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+use traits::pallet_provider::EnrollmentProvider;
+use types::{
+    certificate::{
+        CertificateLeaf, CertificateReason, CertificateStatus, CertificateTransition,
+        NewCertificateParam,
+    },
+    merkle::merkle_proof,
+    primitives::UniqId,
+    professor::{AffiliationRole, NewContractParam, NewOfferParam},
+    student::{NewApplicationParam, NewStudentParam, StudentStatus},
+    university::{EnrollmentSource, FacultyId, NewUniversityParam},
+};
+
+fn register_university() {
+    assert_ok!(University::register_university(
+        RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+        Some(UNIVERSITY),
+        NewUniversityParam {
+            admin: None,
+            permanent_info: ipfs_link(),
+        },
+    ));
+}
+
+fn enroll_student() {
+    apply_to_university();
+    assert_ok!(University::accept_student_application(
+        RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+        APPLICATION,
+    ));
+}
+
+fn apply_to_university() {
+    assert_ok!(Student::register_as_student(
+        RuntimeOrigin::signed(STUDENT_ACCOUNT),
+        Some(STUDENT),
+        NewStudentParam {
+            profile: ipfs_link(),
+        },
+    ));
+    assert_ok!(Student::send_enrollment_application(
+        RuntimeOrigin::signed(STUDENT_ACCOUNT),
+        Some(APPLICATION),
+        NewApplicationParam {
+            university: UNIVERSITY,
+            application: ipfs_link(),
+        },
+    ));
+}
+
+#[test]
+fn accepted_application_enrolls_student_profile() {
+    new_test_ext().execute_with(|| {
+        register_university();
+        apply_to_university();
+        assert_eq!(
+            Student::get_student(&STUDENT).unwrap().status,
+            StudentStatus::Registered
+        );
+
+        assert_ok!(University::accept_student_application(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            APPLICATION,
+        ));
+
+        // enrollment is kept against the student id, not the account
+        assert!(Students::<Test>::contains_key(&UNIVERSITY, &STUDENT));
+        System::assert_has_event(
+            Event::StudentEnrolled(
+                UNIVERSITY,
+                STUDENT,
+                EnrollmentSource::Application(APPLICATION),
+            )
+            .into(),
+        );
+        let student = Student::get_student(&STUDENT).unwrap();
+        assert_eq!(student.enrollments.into_inner(), vec![UNIVERSITY]);
+        assert_eq!(student.status, StudentStatus::Active);
+    });
+}
+
+#[test]
+fn application_cannot_be_accepted_twice() {
+    new_test_ext().execute_with(|| {
+        register_university();
+        apply_to_university();
+
+        assert_ok!(University::accept_student_application(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            APPLICATION,
+        ));
+        assert!(Student::get_application(&APPLICATION).is_none());
+
+        assert_noop!(
+            University::accept_student_application(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                APPLICATION,
+            ),
+            pallet_student::Error::<Test>::NoApplication
+        );
+    });
+}
+
+#[test]
+fn only_admin_can_accept_application() {
+    new_test_ext().execute_with(|| {
+        register_university();
+        apply_to_university();
+
+        assert_noop!(
+            University::accept_student_application(
+                RuntimeOrigin::signed(OTHER_ACCOUNT),
+                APPLICATION,
+            ),
+            Error::<Test>::InsufficientPermission
+        );
+        // application is kept for the admin to respond
+        assert!(Student::get_application(&APPLICATION).is_some());
+    });
+}
+
+#[test]
+fn graduation_keeps_enrollment_record() {
+    new_test_ext().execute_with(|| {
+        register_university();
+        enroll_student();
+        System::set_block_number(5);
+
+        assert_noop!(
+            University::graduate_student(RuntimeOrigin::signed(OTHER_ACCOUNT), UNIVERSITY, STUDENT),
+            Error::<Test>::InsufficientPermission
+        );
+        assert_ok!(University::graduate_student(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            UNIVERSITY,
+            STUDENT,
+        ));
+        System::assert_last_event(Event::StudentGraduated(UNIVERSITY, STUDENT).into());
+
+        let enrollment = University::get_student(&UNIVERSITY, &STUDENT).unwrap();
+        assert_eq!(enrollment.graduated_on, Some(5));
+        assert!(!University::is_enrolled(&UNIVERSITY, &STUDENT));
+
+        let student = Student::get_student(&STUDENT).unwrap();
+        assert!(student.enrollments.is_empty());
+        assert_eq!(student.status, StudentStatus::Graduated);
+
+        assert_noop!(
+            University::graduate_student(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                UNIVERSITY,
+                STUDENT,
+            ),
+            Error::<Test>::AlreadyGraduated
+        );
+        assert_noop!(
+            University::withdraw_enrollment(RuntimeOrigin::signed(STUDENT_ACCOUNT), UNIVERSITY),
+            Error::<Test>::AlreadyGraduated
+        );
+    });
+}
+
+#[test]
+fn withdrawal_removes_enrollment() {
+    new_test_ext().execute_with(|| {
+        register_university();
+        enroll_student();
+
+        assert_ok!(University::withdraw_enrollment(
+            RuntimeOrigin::signed(STUDENT_ACCOUNT),
+            UNIVERSITY,
+        ));
+        System::assert_last_event(Event::StudentWithdrawn(UNIVERSITY, STUDENT).into());
+
+        assert!(!Students::<Test>::contains_key(&UNIVERSITY, &STUDENT));
+        let student = Student::get_student(&STUDENT).unwrap();
+        assert!(student.enrollments.is_empty());
+        assert_eq!(student.status, StudentStatus::Registered);
+
+        assert_noop!(
+            University::withdraw_enrollment(RuntimeOrigin::signed(STUDENT_ACCOUNT), UNIVERSITY),
+            Error::<Test>::NotEnrolled
+        );
+    });
+}
+
+const BATCH: UniqId = UniqId::new([4; 32]);
+
+fn batch_leaves() -> Vec<CertificateLeaf> {
+    (0..3)
+        .map(|i| CertificateLeaf {
+            student: STUDENT,
+            programme: None,
+            document_hash: [i; 32],
+        })
+        .collect()
+}
+
+fn issue_batch() {
+    assert_ok!(University::issue_certificate_batch(
+        RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+        UNIVERSITY,
+        Some(BATCH),
+        batch_leaves().try_into().unwrap(),
+        ipfs_link(),
+    ));
+}
+
+fn batched_status(index: usize) -> CertificateStatus {
+    let leaves = batch_leaves();
+    let hashes: Vec<_> = leaves.iter().map(CertificateLeaf::hash).collect();
+    let verification = University::batched_certificate_verification(
+        &BATCH,
+        &leaves[index],
+        &merkle_proof(&hashes, index).unwrap(),
+        None,
+    )
+    .unwrap();
+    assert!(verification.document_matches);
+    verification.status
+}
+
+#[test]
+fn batch_can_only_be_issued_to_enrolled_students() {
+    new_test_ext().execute_with(|| {
+        register_university();
+        enroll_student();
+        let mut leaves = batch_leaves();
+        leaves.push(CertificateLeaf {
+            student: UniqId::new([9; 32]),
+            programme: None,
+            document_hash: [9; 32],
+        });
+
+        assert_noop!(
+            University::issue_certificate_batch(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                UNIVERSITY,
+                Some(BATCH),
+                leaves.try_into().unwrap(),
+                ipfs_link(),
+            ),
+            Error::<Test>::NotEnrolled
+        );
+        assert_noop!(
+            University::issue_certificate_batch(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                UNIVERSITY,
+                Some(BATCH),
+                Default::default(),
+                ipfs_link(),
+            ),
+            Error::<Test>::EmptyBatch
+        );
+
+        issue_batch();
+        let batch = University::get_certificate_batch(&BATCH).unwrap();
+        assert_eq!(batch.count, 3);
+        for index in 0..3 {
+            assert_eq!(batched_status(index), CertificateStatus::Active);
+        }
+    });
+}
+
+#[test]
+fn batch_transitions_are_recorded_in_history() {
+    new_test_ext().execute_with(|| {
+        register_university();
+        enroll_student();
+        issue_batch();
+        let leaf = batch_leaves()[0].clone();
+
+        System::set_block_number(5);
+        assert_ok!(University::transit_batched_certificate(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            BATCH,
+            leaf.clone(),
+            CertificateStatus::Suspended,
+            CertificateReason::PendingInvestigation,
+            ipfs_link(),
+        ));
+        System::set_block_number(6);
+        assert_ok!(University::transit_certificate_batch(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            BATCH,
+            CertificateStatus::Revoked,
+            CertificateReason::ClericalError,
+            ipfs_link(),
+        ));
+
+        assert_eq!(
+            University::get_batched_certificate_history(&BATCH, &leaf.hash()).into_inner(),
+            vec![CertificateTransition {
+                status: CertificateStatus::Suspended,
+                reason: CertificateReason::PendingInvestigation,
+                evidence: ipfs_link(),
+                at: 5,
+            }]
+        );
+        assert_eq!(
+            University::get_certificate_batch_history(&BATCH).into_inner(),
+            vec![CertificateTransition {
+                status: CertificateStatus::Revoked,
+                reason: CertificateReason::ClericalError,
+                evidence: ipfs_link(),
+                at: 6,
+            }]
+        );
+        // other certificates of the batch have no history of their own
+        assert!(
+            University::get_batched_certificate_history(&BATCH, &batch_leaves()[1].hash())
+                .is_empty()
+        );
+    });
+}
+
+#[test]
+fn batched_certificate_can_be_revoked_individually() {
+    new_test_ext().execute_with(|| {
+        register_university();
+        enroll_student();
+        issue_batch();
+        assert_eq!(batched_status(0), CertificateStatus::Active);
+
+        let leaf = batch_leaves()[0].clone();
+        assert_noop!(
+            University::transit_batched_certificate(
+                RuntimeOrigin::signed(OTHER_ACCOUNT),
+                BATCH,
+                leaf.clone(),
+                CertificateStatus::Revoked,
+                CertificateReason::Fraud,
+                ipfs_link(),
+            ),
+            Error::<Test>::InsufficientPermission
+        );
+        assert_ok!(University::transit_batched_certificate(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            BATCH,
+            leaf.clone(),
+            CertificateStatus::Revoked,
+            CertificateReason::Fraud,
+            ipfs_link(),
+        ));
+        System::assert_last_event(
+            Event::BatchedCertificateStatusChanged {
+                batch_id: BATCH,
+                leaf: leaf.hash(),
+                status: CertificateStatus::Revoked,
+                reason: CertificateReason::Fraud,
+            }
+            .into(),
+        );
+
+        assert_eq!(batched_status(0), CertificateStatus::Revoked);
+        assert_eq!(batched_status(1), CertificateStatus::Active);
+
+        // revoked certificate cannot change anymore
+        assert_noop!(
+            University::transit_batched_certificate(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                BATCH,
+                leaf,
+                CertificateStatus::Active,
+                CertificateReason::InvestigationCleared,
+                ipfs_link(),
+            ),
+            Error::<Test>::InvalidCertificateStatus
+        );
+    });
+}
+
+#[test]
+fn reinstated_batched_certificate_is_active_again() {
+    new_test_ext().execute_with(|| {
+        register_university();
+        enroll_student();
+        issue_batch();
+        let leaf = batch_leaves()[1].clone();
+
+        assert_ok!(University::transit_batched_certificate(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            BATCH,
+            leaf.clone(),
+            CertificateStatus::Suspended,
+            CertificateReason::PendingInvestigation,
+            ipfs_link(),
+        ));
+        assert_eq!(batched_status(1), CertificateStatus::Suspended);
+
+        assert_ok!(University::transit_batched_certificate(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            BATCH,
+            leaf.clone(),
+            CertificateStatus::Active,
+            CertificateReason::InvestigationCleared,
+            ipfs_link(),
+        ));
+        assert_eq!(batched_status(1), CertificateStatus::Active);
+        assert!(!BatchedCertificateStatus::<Test>::contains_key(
+            &BATCH,
+            &leaf.hash()
+        ));
+    });
+}
+
+#[test]
+fn batched_certificate_is_reissued_as_individual_certificate() {
+    new_test_ext().execute_with(|| {
+        register_university();
+        enroll_student();
+        issue_batch();
+        let leaf = batch_leaves()[2].clone();
+        let reissued_as = UniqId::new([5; 32]);
+
+        // replacement have to exist
+        assert_noop!(
+            University::transit_batched_certificate(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                BATCH,
+                leaf.clone(),
+                CertificateStatus::Reissued(reissued_as),
+                CertificateReason::NameChange,
+                ipfs_link(),
+            ),
+            Error::<Test>::NoCertificate
+        );
+
+        assert_ok!(University::issue_certificate(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            UNIVERSITY,
+            Some(reissued_as),
+            STUDENT,
+            NewCertificateParam {
+                programme: None,
+                document: ipfs_link(),
+                document_hash: [9; 32],
+            },
+        ));
+        assert_ok!(University::transit_batched_certificate(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            BATCH,
+            leaf,
+            CertificateStatus::Reissued(reissued_as),
+            CertificateReason::NameChange,
+            ipfs_link(),
+        ));
+        assert_eq!(batched_status(2), CertificateStatus::Reissued(reissued_as));
+    });
+}
+
+#[test]
+fn batch_status_applies_to_every_certificate() {
+    new_test_ext().execute_with(|| {
+        register_university();
+        enroll_student();
+        issue_batch();
+
+        assert_noop!(
+            University::transit_certificate_batch(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                BATCH,
+                CertificateStatus::Reissued(BATCH),
+                CertificateReason::Other,
+                ipfs_link(),
+            ),
+            Error::<Test>::InvalidCertificateStatus
+        );
+        assert_ok!(University::transit_certificate_batch(
+            RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+            BATCH,
+            CertificateStatus::Revoked,
+            CertificateReason::ClericalError,
+            ipfs_link(),
+        ));
+
+        for index in 0..3 {
+            assert_eq!(batched_status(index), CertificateStatus::Revoked);
+        }
+        // individual certificates of an invalidated batch cannot be changed
+        assert_noop!(
+            University::transit_batched_certificate(
+                RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+                BATCH,
+                batch_leaves()[0].clone(),
+                CertificateStatus::Suspended,
+                CertificateReason::Other,
+                ipfs_link(),
+            ),
+            Error::<Test>::InvalidCertificateStatus
+        );
+    });
+}
+
+fn propose_contract(faculty: Option<FacultyId>) -> DispatchResult {
+    University::add_professor(
+        RuntimeOrigin::signed(UNIVERSITY_ADMIN),
+        UNIVERSITY,
+        PROFESSOR,
+        NewContractParam {
+            role: AffiliationRole::Lecturer,
+            faculty,
+            contract: ipfs_link(),
+            validity: 5,
+        },
+    )
+}
+
+#[test]
+fn contract_is_made_to_professor_as_an_offer() {
+    new_test_ext().execute_with(|| {
+        register_university();
+        let faculty = UniqId::new([9; 32]);
+
+        assert_ok!(propose_contract(Some(faculty)));
+
+        let offers = offers_made();
+        assert_eq!(
+            offers,
+            vec![(
+                UNIVERSITY_ADMIN,
+                NewOfferParam {
+                    professor: PROFESSOR,
+                    university: UNIVERSITY,
+                    contract_file: ipfs_link(),
+                    role: AffiliationRole::Lecturer,
+                    faculty: Some(faculty),
+                    validity: 5,
+                },
+            )]
+        );
+        System::assert_last_event(
+            Event::ContractProposed {
+                university: UNIVERSITY,
+                professor: PROFESSOR,
+                offer: offer_id_of(0),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn contract_is_proposed_only_by_university_admin() {
+    new_test_ext().execute_with(|| {
+        register_university();
+
+        assert_noop!(
+            University::add_professor(
+                RuntimeOrigin::signed(OTHER_ACCOUNT),
+                UNIVERSITY,
+                PROFESSOR,
+                NewContractParam {
+                    role: AffiliationRole::Lecturer,
+                    faculty: None,
+                    contract: ipfs_link(),
+                    validity: 5,
+                },
+            ),
+            Error::<Test>::InsufficientPermission
+        );
+        assert!(offers_made().is_empty());
+    });
+}
@@ -134,6 +134,9 @@ pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
 pub const HOURS: BlockNumber = MINUTES * 60;
 pub const DAYS: BlockNumber = HOURS * 24;
 
+/// Maximum number of items returned by a single paginated query
+pub const MAX_QUERY_PAGE_SIZE: u32 = 100;
+
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
 pub fn native_version() -> NativeVersion {
@@ -283,6 +286,11 @@ impl pallet_sudo::Config for Runtime {
     type RuntimeCall = RuntimeCall;
 }
 
+parameter_types! {
+    /// no validation is required to issue certificates or announce intakes
+    pub TrustedValidators: Vec<types::validator::ValidatorId> = Vec::new();
+}
+
 impl pallet_university::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type ProfessorProvider = Professor;
@@ -290,12 +298,23 @@ impl pallet_university::Config for Runtime {
     type LectureProvider = Lecture;
     type ExamProvider = Exam;
     type ScholarshipProvider = Scholarship;
+    type ValidationProvider = CheckedValidation;
+    type TrustedValidators = TrustedValidators;
+    type MaxCertificateTransitions = ConstU32<32>;
+    type MaxCertificatesPerBatch = ConstU32<1024>;
 }
 
 impl pallet_professor::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type UniversityProvider = University;
     type StudentProvider = Student;
+    type Currency = Balances;
+    type OfferDepositBase = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type OfferDepositPerBlock = ConstU128<{ EXISTENTIAL_DEPOSIT / 100 }>;
+    type MaxOfferValidity = ConstU32<{ 30 * DAYS }>;
+    type MaxOfferExpiriesPerBlock = ConstU32<64>;
+    type MaxOpenOffersPerProfessor = ConstU32<16>;
+    type MaxOpenOffersPerUniversity = ConstU32<256>;
 }
 
 impl pallet_student::Config for Runtime {
@@ -333,12 +352,30 @@ impl pallet_checked_validation::Config for Runtime {
     type UniversityProvider = University;
     type ProfessorProvider = Professor;
     type StudentProvider = Student;
+    type Currency = Balances;
+    type ValidatorDeposit = ConstU128<{ 1_000 * EXISTENTIAL_DEPOSIT }>;
+    type MaxValidityPeriod = ConstU32<{ 5 * 365 * DAYS }>;
+    type MaxValidationExpiriesPerBlock = ConstU32<64>;
 }
 
 impl pallet_intake::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type ProfessorProvider = Professor;
     type UniversityProvider = University;
+    type StudentProvider = Student;
+    type EnrollmentProvider = University;
+    type ValidationProvider = CheckedValidation;
+    type TrustedValidators = TrustedValidators;
+    type MaxIntakeTransitionsPerBlock = ConstU32<64>;
+    type MaxWaitlistLength = ConstU32<256>;
+    type MaxAcceptApplications = ConstU32<100>;
+    type MaxAutoAdmissionApplicants = ConstU32<1_000>;
+    type MaxApplicationRemovalsPerBlock = ConstU32<256>;
+    type OfferResponsePeriod = ConstU32<{ 7 * DAYS }>;
+    // block authors can predict and bias collective flip,
+    // acceptable for testnet but lottery admission is not fair against them
+    type Randomness = RandomnessCollectiveFlip;
+    type WeightInfo = pallet_intake::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -415,6 +452,7 @@ mod benches {
         [pallet_timestamp, Timestamp]
 
         [pallet_university, University]
+        [pallet_intake, Intake]
         [pallet_professor, Professor]
         [pallet_student, Student]
         [pallet_lecture, Lecture]
@@ -572,6 +610,184 @@ impl_runtime_apis! {
         }
     }
 
+    impl chain_rpc_runtime_api::university::PalletUniversityApi<Block> for Runtime {
+        fn universities_keys() -> Vec<types::university::UniversityId> {
+            pallet_university::Universities::<Runtime>::iter_keys().collect()
+        }
+
+        fn university_by_id(
+            id: types::university::UniversityId,
+        ) -> Option<types::university::University<AccountId>> {
+            University::university_info(&id)
+        }
+
+        fn universities(
+            start: Option<types::university::UniversityId>,
+            limit: u32,
+        ) -> types::primitives::Page<types::university::UniversityId, types::university::University<AccountId>> {
+            traits::pagination::map_page::<pallet_university::Universities<Runtime>, _, _>(
+                start,
+                limit.min(MAX_QUERY_PAGE_SIZE),
+            )
+        }
+    }
+
+    impl chain_rpc_runtime_api::professor::PalletProfessorApi<Block, BlockNumber> for Runtime {
+        fn professors(
+            start: Option<types::professor::ProfessorId>,
+            limit: u32,
+        ) -> types::primitives::Page<types::professor::ProfessorId, types::professor::ProfessorInfo<AccountId>> {
+            traits::pagination::map_page::<pallet_professor::Professors<Runtime>, _, _>(
+                start,
+                limit.min(MAX_QUERY_PAGE_SIZE),
+            )
+        }
+
+        fn offers(
+            start: Option<types::professor::OfferId>,
+            limit: u32,
+        ) -> types::primitives::Page<types::professor::OfferId, types::professor::OfferInfo<BlockNumber>> {
+            traits::pagination::map_page::<pallet_professor::Offers<Runtime>, _, _>(
+                start,
+                limit.min(MAX_QUERY_PAGE_SIZE),
+            )
+        }
+
+        fn professor_offers(
+            professor: types::professor::ProfessorId,
+            start: Option<types::professor::OfferId>,
+            limit: u32,
+        ) -> types::primitives::Page<types::professor::OfferId, types::professor::OfferInfo<BlockNumber>> {
+            Professor::professor_offers_page(&professor, start, limit.min(MAX_QUERY_PAGE_SIZE))
+        }
+
+        fn affiliations(
+            professor: types::professor::ProfessorId,
+            start: Option<types::university::UniversityId>,
+            limit: u32,
+        ) -> types::primitives::Page<
+            types::university::UniversityId,
+            types::professor::Affiliation<BlockNumber>,
+        > {
+            traits::pagination::double_map_page::<pallet_professor::Affiliations<Runtime>, _, _, _>(
+                professor,
+                start,
+                limit.min(MAX_QUERY_PAGE_SIZE),
+            )
+        }
+
+        fn faculty(
+            university: types::university::UniversityId,
+            start: Option<types::professor::ProfessorId>,
+            limit: u32,
+        ) -> types::primitives::Page<
+            types::professor::ProfessorId,
+            types::professor::Affiliation<BlockNumber>,
+        > {
+            University::faculty_page(&university, start, limit.min(MAX_QUERY_PAGE_SIZE))
+        }
+    }
+
+    impl chain_rpc_runtime_api::intake::PalletIntakeApi<Block, BlockNumber> for Runtime {
+        fn intakes(
+            university: types::university::UniversityId,
+            start: Option<u32>,
+            limit: u32,
+        ) -> types::primitives::Page<
+            types::intake::IntakeId<types::university::UniversityId>,
+            types::intake::IntakeInfo<BlockNumber>,
+        > {
+            Intake::intakes_page(&university, start, limit.min(MAX_QUERY_PAGE_SIZE))
+        }
+
+        fn applications(
+            intake: types::intake::IntakeId<types::university::UniversityId>,
+            start: Option<AccountId>,
+            limit: u32,
+        ) -> types::primitives::Page<AccountId, types::intake::IntakeApplication<BlockNumber>> {
+            traits::pagination::double_map_page::<pallet_intake::Applications<Runtime>, _, _, _>(
+                intake,
+                start,
+                limit.min(MAX_QUERY_PAGE_SIZE),
+            )
+        }
+
+        fn accepted_applications(
+            intake: types::intake::IntakeId<types::university::UniversityId>,
+            start: Option<AccountId>,
+            limit: u32,
+        ) -> types::primitives::Page<AccountId, types::intake::AdmissionOffer<BlockNumber>> {
+            traits::pagination::double_map_page::<pallet_intake::AcceptedApplications<Runtime>, _, _, _>(
+                intake,
+                start,
+                limit.min(MAX_QUERY_PAGE_SIZE),
+            )
+        }
+    }
+
+    impl chain_rpc_runtime_api::validation::PalletValidationApi<Block, BlockNumber> for Runtime {
+        fn root_validator() -> Option<types::validator::ValidatorId> {
+            CheckedValidation::root_validator()
+        }
+
+        fn validators(
+            start: Option<types::validator::ValidatorId>,
+            limit: u32,
+        ) -> types::primitives::Page<types::validator::ValidatorId, types::validator::ValidatorInfo<AccountId>> {
+            traits::pagination::map_page::<pallet_checked_validation::Validators<Runtime>, _, _>(
+                start,
+                limit.min(MAX_QUERY_PAGE_SIZE),
+            )
+        }
+
+        fn validated_universities(
+            validator: types::validator::ValidatorId,
+            start: Option<types::university::UniversityId>,
+            limit: u32,
+        ) -> types::primitives::Page<types::university::UniversityId, types::validator::ValidatedUniversityInfo<BlockNumber>> {
+            traits::pagination::double_map_page::<pallet_checked_validation::Universities<Runtime>, _, _, _>(
+                validator,
+                start,
+                limit.min(MAX_QUERY_PAGE_SIZE),
+            )
+        }
+
+        fn validated_professors(
+            validator: types::validator::ValidatorId,
+            start: Option<types::professor::ProfessorId>,
+            limit: u32,
+        ) -> types::primitives::Page<types::professor::ProfessorId, types::validator::ValidatedProfessorInfo<BlockNumber>> {
+            traits::pagination::double_map_page::<pallet_checked_validation::Professors<Runtime>, _, _, _>(
+                validator,
+                start,
+                limit.min(MAX_QUERY_PAGE_SIZE),
+            )
+        }
+    }
+
+    impl chain_rpc_runtime_api::certificate::PalletCertificateApi<Block, BlockNumber> for Runtime {
+        fn certificate_verify(
+            certificate_id: types::certificate::CertificateId,
+            document_hash: types::certificate::DocumentHash,
+            validator: Option<types::validator::ValidatorId>,
+        ) -> Option<types::certificate::CertificateVerification<BlockNumber>> {
+            University::certificate_verification(&certificate_id, &document_hash, validator)
+        }
+
+        fn certificate_batch_verify(
+            batch_id: types::certificate::CertificateBatchId,
+            leaf: types::certificate::CertificateLeaf,
+            proof: Vec<types::merkle::MerkleHash>,
+            validator: Option<types::validator::ValidatorId>,
+        ) -> Option<types::certificate::CertificateVerification<BlockNumber>> {
+            University::batched_certificate_verification(&batch_id, &leaf, &proof, validator)
+        }
+
+        fn block_timestamp() -> u64 {
+            Timestamp::get()
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
@@ -1,3 +1,5 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod id_generator;
+pub mod pagination;
 pub mod pallet_provider;
@@ -0,0 +1,58 @@
+use frame_support::{
+    codec::FullCodec,
+    storage::{IterableStorageDoubleMap, IterableStorageMap, StorageDoubleMap, StorageMap},
+};
+use sp_std::vec::Vec;
+use types::primitives::Page;
+
+/// collect at most `limit` items into a page
+/// next page starts after the last collected key if there are items left
+pub fn collect_page<Key: Clone, Item>(
+    mut items: impl Iterator<Item = (Key, Item)>,
+    limit: u32,
+) -> Page<Key, Item> {
+    let limit = limit as usize;
+    let page = items.by_ref().take(limit).collect::<Vec<_>>();
+    let next = if page.len() == limit && items.next().is_some() {
+        page.last().map(|(key, _)| key.clone())
+    } else {
+        None
+    };
+
+    Page { items: page, next }
+}
+
+/// page of storage map `Map` starting after key `start`
+pub fn map_page<Map, Key, Item>(start: Option<Key>, limit: u32) -> Page<Key, Item>
+where
+    Map: IterableStorageMap<Key, Item> + StorageMap<Key, Item>,
+    Key: FullCodec + Clone,
+    Item: FullCodec,
+{
+    match start {
+        Some(start) => collect_page(Map::iter_from(Map::hashed_key_for(start)), limit),
+        None => collect_page(Map::iter(), limit),
+    }
+}
+
+/// page of entries of storage double map `Map` under `first_key`
+/// starting after second key `start`
+pub fn double_map_page<Map, FirstKey, Key, Item>(
+    first_key: FirstKey,
+    start: Option<Key>,
+    limit: u32,
+) -> Page<Key, Item>
+where
+    Map: IterableStorageDoubleMap<FirstKey, Key, Item> + StorageDoubleMap<FirstKey, Key, Item>,
+    FirstKey: FullCodec,
+    Key: FullCodec + Clone,
+    Item: FullCodec,
+{
+    match start {
+        Some(start) => collect_page(
+            Map::iter_prefix_from(&first_key, Map::hashed_key_for(&first_key, start)),
+            limit,
+        ),
+        None => collect_page(Map::iter_prefix(first_key), limit),
+    }
+}
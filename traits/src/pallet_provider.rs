@@ -1,14 +1,39 @@
 use frame_support::pallet_prelude::*;
+use sp_std::vec::Vec;
 
 pub trait StudentProvider {
     type StudentId;
+    type StudentInfo;
     type ApplicationId;
     type ApplicationInfo;
+    type FrameConfig: frame_system::Config;
 
-    fn get_student_info();
+    fn student_info(student_id: &Self::StudentId) -> Option<Self::StudentInfo>;
+    /// student id registered by `account`
+    fn student_of(account: &types::AccountIdOf<Self::FrameConfig>) -> Option<Self::StudentId>;
+    /// record in the profile of `student_id`
+    /// that it have been enrolled in `university_id`
+    fn note_enrollment(
+        student_id: &Self::StudentId,
+        university_id: &types::university::UniversityId,
+    ) -> DispatchResult;
+    /// record in the profile of `student_id`
+    /// that it have graduated from `university_id`
+    fn note_graduation(
+        student_id: &Self::StudentId,
+        university_id: &types::university::UniversityId,
+    ) -> DispatchResult;
+    /// record in the profile of `student_id`
+    /// that it have withdrawn from `university_id`
+    fn note_withdrawal(
+        student_id: &Self::StudentId,
+        university_id: &types::university::UniversityId,
+    ) -> DispatchResult;
     fn application_info(
         application_id: &Self::ApplicationId,
     ) -> Result<Self::ApplicationInfo, DispatchError>;
+    /// remove the application once university have responded to it
+    fn close_application(application_id: &Self::ApplicationId) -> DispatchResult;
 }
 
 pub trait UniversityProvider {
@@ -20,13 +45,50 @@ pub trait UniversityProvider {
     fn university_admin(
         university_id: &Self::UniversityId,
     ) -> Option<types::AccountIdOf<Self::FrameConfig>>;
+    /// record that professor have joined the faculty of university
+    fn note_affiliation(
+        university_id: &Self::UniversityId,
+        professor_id: &types::professor::ProfessorId,
+    ) -> DispatchResult;
+    /// record that professor is no longer in the faculty of university
+    fn note_disaffiliation(
+        university_id: &Self::UniversityId,
+        professor_id: &types::professor::ProfessorId,
+    );
+
+    /// register a university administered by `admin`
+    /// used by other pallets to setup their benchmarks
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register_university_for_benchmark(
+        admin: types::AccountIdOf<Self::FrameConfig>,
+    ) -> Self::UniversityId;
 }
 
 pub trait ProfessorProvider {
     type ProfessorId; // types::professor::ProfessorIdDef;
     type ProfessorInfo;
+    type AffiliationInfo;
+    type FrameConfig: frame_system::Config;
 
     fn professor_info(professor_id: &Self::ProfessorId) -> Option<Self::ProfessorInfo>;
+    /// affiliation of professor with university
+    /// ended affiliations are kept as well
+    fn affiliation_info(
+        professor_id: &Self::ProfessorId,
+        university_id: &types::university::UniversityId,
+    ) -> Option<Self::AffiliationInfo>;
+    /// whether professor is currently affiliated with university
+    fn is_affiliated(
+        professor_id: &Self::ProfessorId,
+        university_id: &types::university::UniversityId,
+    ) -> bool;
+    /// make an offer to professor on behalf of university admin `who`
+    /// professor responds to it like any other offer
+    /// and is affiliated with university once it is accepted
+    fn make_offer(
+        who: &types::AccountIdOf<Self::FrameConfig>,
+        offer: types::professor::NewOfferParam<types::BlockNumberOf<Self::FrameConfig>>,
+    ) -> Result<types::professor::OfferId, DispatchError>;
 }
 
 pub trait IntakeProvider {
@@ -36,18 +98,112 @@ pub trait IntakeProvider {
     fn intake_info(intake_id: &Self::IntakeId) -> Option<Self::IntakeInfo>;
 }
 
+/// Enrollment of students in universities
+///
+/// every way of admitting a student ends up in `enroll`
+/// so other pallets only have to ask this provider whether a student is enrolled
+pub trait EnrollmentProvider {
+    type UniversityId;
+    type StudentId;
+    type EnrollmentInfo;
+
+    fn enrollment_info(
+        university_id: &Self::UniversityId,
+        student_id: &Self::StudentId,
+    ) -> Option<Self::EnrollmentInfo>;
+
+    fn is_enrolled(university_id: &Self::UniversityId, student_id: &Self::StudentId) -> bool {
+        Self::enrollment_info(university_id, student_id).is_some()
+    }
+
+    /// enroll `student_id` in `university_id`
+    /// fails if university does not exists or student is already enrolled
+    fn enroll(
+        university_id: &Self::UniversityId,
+        student_id: &Self::StudentId,
+        source: types::university::EnrollmentSource,
+        programme: Option<types::university::ProgrammeId>,
+    ) -> DispatchResult;
+}
+
 pub trait LectureProvider {
-    fn get_lecture_info();
+    type LectureId;
+    type LectureInfo;
+
+    fn lecture_info(lecture_id: &Self::LectureId) -> Option<Self::LectureInfo>;
 }
 
 pub trait ExamProvider {
-    fn get_exam_info();
+    type ExamId;
+    type ExamInfo;
+
+    fn exam_info(exam_id: &Self::ExamId) -> Option<Self::ExamInfo>;
 }
 
 pub trait ScholarshipProvider {
-    fn get_scholarship_info();
+    type ScholarshipId;
+    type ScholarshipInfo;
+
+    fn scholarship_info(scholarship_id: &Self::ScholarshipId) -> Option<Self::ScholarshipInfo>;
 }
 
 pub trait ValidationProvider {
-    fn is_verified_university() -> bool;
+    type ValidatorId;
+    type UniversityId;
+    type ProfessorId;
+    type ValidatedUniversityInfo;
+    type ValidatedProfessorInfo;
+
+    fn validated_university_info(
+        validator_id: &Self::ValidatorId,
+        university_id: &Self::UniversityId,
+    ) -> Option<Self::ValidatedUniversityInfo>;
+    fn validated_professor_info(
+        validator_id: &Self::ValidatorId,
+        professor_id: &Self::ProfessorId,
+    ) -> Option<Self::ValidatedProfessorInfo>;
+
+    /// tier of the validator if it is registered
+    fn validator_tier(validator_id: &Self::ValidatorId) -> Option<types::validator::ValidatorTier>;
+
+    /// validators currently vouching for university
+    fn university_validators(university_id: &Self::UniversityId) -> Vec<Self::ValidatorId>;
+    /// validators currently vouching for professor
+    fn professor_validators(professor_id: &Self::ProfessorId) -> Vec<Self::ValidatorId>;
+
+    /// whether `university_id` have been validated by `validator_id`
+    fn is_verified_university(
+        validator_id: &Self::ValidatorId,
+        university_id: &Self::UniversityId,
+    ) -> bool {
+        Self::validated_university_info(validator_id, university_id).is_some()
+    }
+
+    /// whether `professor_id` have been validated by `validator_id`
+    fn is_verified_professor(
+        validator_id: &Self::ValidatorId,
+        professor_id: &Self::ProfessorId,
+    ) -> bool {
+        Self::validated_professor_info(validator_id, professor_id).is_some()
+    }
+
+    /// whether `university_id` have been validated by any validator in `trust_set`
+    fn is_university_trusted_by(
+        university_id: &Self::UniversityId,
+        trust_set: &[Self::ValidatorId],
+    ) -> bool {
+        trust_set
+            .iter()
+            .any(|validator_id| Self::is_verified_university(validator_id, university_id))
+    }
+
+    /// whether `professor_id` have been validated by any validator in `trust_set`
+    fn is_professor_trusted_by(
+        professor_id: &Self::ProfessorId,
+        trust_set: &[Self::ValidatorId],
+    ) -> bool {
+        trust_set
+            .iter()
+            .any(|validator_id| Self::is_verified_professor(validator_id, professor_id))
+    }
 }
@@ -0,0 +1,160 @@
+/// Identifier type to uniquely represent a certificate
+pub type CertificateId = crate::primitives::UniqId;
+
+/// Identifier type to uniquely represent a batch of certificates
+pub type CertificateBatchId = crate::primitives::UniqId;
+
+/// blake2_256 hash of the certificate document
+pub type DocumentHash = [u8; 32];
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_std::fmt::Debug;
+
+use crate::{
+    merkle::{hash_leaf, MerkleHash},
+    student::StudentId,
+    university::{ProgrammeId, UniversityId},
+    StdIpfsLink,
+};
+
+/// Required paramater to issue a certificate
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct NewCertificateParam {
+    /// degree / programme this certificate is awarded for
+    /// defaults to the programme student is enrolled in
+    pub programme: Option<ProgrammeId>,
+    /// ipfs link to certificate document
+    pub document: StdIpfsLink,
+    pub document_hash: DocumentHash,
+}
+
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub enum CertificateStatus {
+    /// Certificate is valid
+    Active,
+    /// Certificate is temporarily not valid
+    Suspended,
+    /// Certificate have been permanently invalidated
+    Revoked,
+    /// Certificate have been replaced by this certificate
+    Reissued(CertificateId),
+}
+
+impl CertificateStatus {
+    /// whether certificate can still change it's status
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            CertificateStatus::Revoked | CertificateStatus::Reissued(..)
+        )
+    }
+
+    /// whether certificate in this status can be moved to `next`
+    pub fn can_move_to(&self, next: &CertificateStatus) -> bool {
+        match (self, next) {
+            (current, _) if current.is_final() => false,
+            (CertificateStatus::Active, CertificateStatus::Suspended)
+            | (CertificateStatus::Suspended, CertificateStatus::Active) => true,
+            (_, CertificateStatus::Revoked) | (_, CertificateStatus::Reissued(..)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Why the status of certificate have been changed
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+pub enum CertificateReason {
+    /// Certificate was obtained by fraud
+    Fraud,
+    /// Certificate was issued with incorrect details
+    ClericalError,
+    /// Certificate is under investigation
+    PendingInvestigation,
+    /// Investigation have been concluded in favour of the holder
+    InvestigationCleared,
+    /// Holder have changed the name
+    NameChange,
+    Other,
+}
+
+/// Single status change of a certificate
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+pub struct CertificateTransition<BlockNumber> {
+    /// status certificate have been moved to
+    pub status: CertificateStatus,
+    pub reason: CertificateReason,
+    /// ipfs link to supporting documents
+    pub evidence: StdIpfsLink,
+    /// block number when the transition happened
+    pub at: BlockNumber,
+}
+
+/// information stored of a certificate
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+pub struct Certificate<BlockNumber> {
+    /// university issuing this certificate
+    pub university: UniversityId,
+    pub student: StudentId,
+    pub programme: Option<ProgrammeId>,
+    /// block number when certificate was issued
+    pub issued_on: BlockNumber,
+    pub document: StdIpfsLink,
+    pub document_hash: DocumentHash,
+    pub status: CertificateStatus,
+    /// certificate this one have been reissued for
+    pub reissue_of: Option<CertificateId>,
+}
+
+/// Certificate included in a batch
+/// leaf of the batch merkle tree is `hash_leaf` of this encoded leaf
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct CertificateLeaf {
+    pub student: StudentId,
+    pub programme: Option<ProgrammeId>,
+    pub document_hash: DocumentHash,
+}
+
+impl CertificateLeaf {
+    pub fn hash(&self) -> MerkleHash {
+        hash_leaf(&self.encode())
+    }
+}
+
+/// information stored of a batch of certificates
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+pub struct CertificateBatch<BlockNumber> {
+    /// university issuing this batch
+    pub university: UniversityId,
+    pub root: MerkleHash,
+    pub count: u32,
+    /// block number when batch was issued
+    pub issued_on: BlockNumber,
+    pub manifest: StdIpfsLink,
+    /// status of the whole batch
+    /// takes precedence over status of individual certificates unless `Active`
+    pub status: CertificateStatus,
+}
+
+/// Result of verifying a certificate presented by it's holder
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct CertificateVerification<BlockNumber> {
+    /// university that issued the certificate
+    pub issuer: UniversityId,
+    /// student holding the certificate
+    pub holder: StudentId,
+    pub programme: Option<ProgrammeId>,
+    pub issued_on: BlockNumber,
+    pub status: CertificateStatus,
+    /// whether presented document hash is the one recorded on chain
+    pub document_matches: bool,
+    /// whether issuer is validated by the requested validator
+    /// `None` if no validator was requested
+    pub issuer_validated: Option<bool>,
+}
+
+pub type CertificateInfoFor<T> = Certificate<crate::BlockNumberOf<T>>;
+pub type CertificateTransitionFor<T> = CertificateTransition<crate::BlockNumberOf<T>>;
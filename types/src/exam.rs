@@ -1,12 +1,26 @@
 /// Identifier type to uniquely represent a exam
 pub type ExamId = crate::primitives::UniqId;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::fmt::Debug;
 
+use crate::{lecture::LectureId, professor::ProfessorId, university::UniversityId, StdIpfsLink};
+
 #[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug)]
-pub struct NewExamParam {}
+pub struct NewExamParam {
+    /// lecture this exam is taken for, if any
+    pub lecture: Option<LectureId>,
+    /// ipfs link to exam details
+    pub details: StdIpfsLink,
+}
 
 /// information stored of a exam
-pub struct Exam {}
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+pub struct Exam {
+    pub university: UniversityId,
+    /// professor invigilating this exam
+    pub professor: ProfessorId,
+    pub lecture: Option<LectureId>,
+    pub details: StdIpfsLink,
+}
@@ -1,10 +1,12 @@
-use crate::primitives::StdIpfsLink;
+use crate::{primitives::StdIpfsLink, university::ProgrammeId};
 use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec};
 use scale_info::TypeInfo;
 use sp_std::fmt::Debug;
 
 // Uniquely identify a intake
 #[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub struct IntakeId<UniversityId> {
     pub university_id: UniversityId,
     pub intake_index: u32,
@@ -19,19 +21,52 @@ pub struct NewIntakeParam<BlockNumber> {
     pub application_closes: BlockNumber,
     pub max_applicants: u32,
     pub max_accepted: u32,
+    pub admission_policy: AdmissionPolicy,
+    /// programme the admitted students are enrolled in
+    pub programme: Option<ProgrammeId>,
 }
 
 /// Information of a lecture
 #[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub struct IntakeInfo<BlockNumber> {
     pub application_opens: BlockNumber,
     pub application_closes: BlockNumber,
     pub max_applicants: u32,
     pub max_accepted: u32,
     pub status: IntakeStatus,
+    pub admission_policy: AdmissionPolicy,
+    pub programme: Option<ProgrammeId>,
 }
 
+/// How the applicants of an intake are admitted
 #[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub enum AdmissionPolicy {
+    /// University accepts the applications by itself
+    Manual,
+    /// Applications are accepted in order of `applied_on`
+    FirstComeFirstServed,
+    /// Applications are accepted in order of score given by university while reviewing
+    /// applications that were not scored are never accepted automatically
+    ScoreBased,
+    /// Applications are accepted in random order
+    /// fairness depends on the randomness source configured in runtime
+    Lottery,
+}
+
+impl AdmissionPolicy {
+    /// whether chain admits the applicants itself when intake closes
+    pub fn is_automatic(&self) -> bool {
+        !matches!(self, AdmissionPolicy::Manual)
+    }
+}
+
+/// score given to an application while reviewing it
+pub type AdmissionScore = u32;
+
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub enum IntakeStatus {
     /// Intake is pending to be open
     IntakePending,
@@ -44,6 +79,7 @@ pub enum IntakeStatus {
 }
 
 #[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub struct IntakeApplication<BlockNumber> {
     /// block number when application was made
     pub applied_on: BlockNumber,
@@ -51,5 +87,57 @@ pub struct IntakeApplication<BlockNumber> {
     pub info: StdIpfsLink,
 }
 
+/// Running counts of an intake used to enforce it's quotas
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen, Default)]
+pub struct IntakeCounter {
+    /// number of applications currently held against `max_applicants`
+    pub applicants: u32,
+    /// number of applications accepted against `max_accepted`
+    pub accepted: u32,
+    /// number of accepted applications yet to respond to their offer
+    pub pending_offers: u32,
+}
+
+/// Offer of enrollment made to an accepted applicant
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct AdmissionOffer<BlockNumber> {
+    /// block number when the application was accepted
+    pub offered_on: BlockNumber,
+    /// offer lapses on this block if applicant does not respond before
+    pub respond_by: BlockNumber,
+    pub status: AdmissionOfferStatus,
+}
+
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub enum AdmissionOfferStatus {
+    /// Waiting for applicant to confirm or decline
+    Pending,
+    /// Applicant confirmed the enrollment
+    Confirmed,
+}
+
+/// Application waiting for a seat after intake reached it's `max_applicants`
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+pub struct WaitlistEntry<StudentId, BlockNumber> {
+    pub student: StudentId,
+    pub application: IntakeApplication<BlockNumber>,
+}
+
 /// limit on how many application can be accepted at once
 pub type AcceptApplicationLimit = u32;
+
+const MAX_CLEANUP_CURSOR_LENGTH: u32 = 256;
+
+/// storage key to continue removing entries of finalised intake from
+pub type CleanupCursor = BoundedVec<u8, ConstU32<MAX_CLEANUP_CURSOR_LENGTH>>;
+
+/// Progress of removing the applications of a finalised intake
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+pub enum IntakeCleanup {
+    /// removing applications
+    Applications(Option<CleanupCursor>),
+    /// applications are removed, removing their scores
+    Scores(Option<CleanupCursor>),
+}
@@ -1,13 +1,24 @@
 /// Identifier type to uniquely represent a lecture
 pub type LectureId = crate::primitives::UniqId;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::fmt::Debug;
 
+use crate::{professor::ProfessorId, university::UniversityId, StdIpfsLink};
+
 /// Required paramater to register this lecture
 #[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug)]
-pub struct NewLectureParam {}
+pub struct NewLectureParam {
+    /// ipfs link to permanent lecture details
+    pub details: StdIpfsLink,
+}
 
 /// Information of a lecture
-pub struct Lecture {}
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+pub struct Lecture {
+    pub university: UniversityId,
+    /// professor taking this lecture
+    pub professor: ProfessorId,
+    pub details: StdIpfsLink,
+}
@@ -1,8 +1,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod certificate;
 pub mod exam;
 pub mod intake;
 pub mod lecture;
+pub mod merkle;
 pub mod primitives;
 pub mod professor;
 pub mod scholarship;
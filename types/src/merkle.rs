@@ -0,0 +1,183 @@
+//! Binary merkle tree used to issue a batch of items under a single root
+//!
+//! leaves and inner nodes are hashed with different prefix so a node can never be presented
+//! as a leaf. children of a node are sorted before hashing so a proof is only the list of
+//! siblings from leaf to root. last node of a level without sibling is promoted as is.
+
+use sp_std::vec::Vec;
+
+pub type MerkleHash = [u8; 32];
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// hash of a leaf holding `data`
+pub fn hash_leaf(data: &[u8]) -> MerkleHash {
+    let mut preimage = Vec::with_capacity(data.len() + 1);
+    preimage.push(LEAF_PREFIX);
+    preimage.extend_from_slice(data);
+    sp_io::hashing::blake2_256(&preimage)
+}
+
+/// hash of a node with children `a` and `b` in any order
+pub fn hash_node(a: &MerkleHash, b: &MerkleHash) -> MerkleHash {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut preimage = [0_u8; 65];
+    preimage[0] = NODE_PREFIX;
+    preimage[1..33].copy_from_slice(left);
+    preimage[33..].copy_from_slice(right);
+    sp_io::hashing::blake2_256(&preimage)
+}
+
+fn next_level(level: &[MerkleHash]) -> Vec<MerkleHash> {
+    level
+        .chunks(2)
+        .map(|pair| {
+            pair.get(1)
+                .map_or(pair[0], |right| hash_node(&pair[0], right))
+        })
+        .collect()
+}
+
+/// root of the tree with `leaves`
+/// return `None` if there is no leaf
+pub fn merkle_root(leaves: &[MerkleHash]) -> Option<MerkleHash> {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level.first().copied()
+}
+
+/// siblings from leaf at `index` to the root
+/// return `None` if there is no leaf at `index`
+pub fn merkle_proof(leaves: &[MerkleHash], mut index: usize) -> Option<Vec<MerkleHash>> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        index /= 2;
+        level = next_level(&level);
+    }
+    Some(proof)
+}
+
+/// whether `leaf` is included in the tree with `root`
+pub fn verify_proof(root: &MerkleHash, leaf: &MerkleHash, proof: &[MerkleHash]) -> bool {
+    proof
+        .iter()
+        .fold(*leaf, |node, sibling| hash_node(&node, sibling))
+        == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(count: u8) -> Vec<MerkleHash> {
+        (0..count).map(|i| hash_leaf(&[i])).collect()
+    }
+
+    fn assert_every_leaf_proves(leaves: &[MerkleHash]) {
+        let root = merkle_root(leaves).unwrap();
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = merkle_proof(leaves, index).unwrap();
+            assert!(
+                verify_proof(&root, leaf, &proof),
+                "leaf {} of {}",
+                index,
+                leaves.len()
+            );
+        }
+    }
+
+    #[test]
+    fn empty_tree_has_no_root() {
+        assert_eq!(merkle_root(&[]), None);
+        assert_eq!(merkle_proof(&[], 0), None);
+    }
+
+    #[test]
+    fn single_leaf_is_the_root() {
+        let leaves = leaves(1);
+        assert_eq!(merkle_root(&leaves), Some(leaves[0]));
+        assert_eq!(merkle_proof(&leaves, 0), Some(Vec::new()));
+        assert!(verify_proof(&leaves[0], &leaves[0], &[]));
+    }
+
+    #[test]
+    fn proofs_verify_for_every_leaf_count() {
+        for count in 2..=9 {
+            assert_every_leaf_proves(&leaves(count));
+        }
+    }
+
+    #[test]
+    fn last_leaf_of_odd_level_is_promoted() {
+        let leaves = leaves(3);
+        let root = hash_node(&hash_node(&leaves[0], &leaves[1]), &leaves[2]);
+        assert_eq!(merkle_root(&leaves), Some(root));
+        assert_eq!(
+            merkle_proof(&leaves, 2),
+            Some(vec![hash_node(&leaves[0], &leaves[1])])
+        );
+    }
+
+    #[test]
+    fn proof_out_of_range_is_none() {
+        assert_eq!(merkle_proof(&leaves(3), 3), None);
+    }
+
+    #[test]
+    fn tampered_proof_fails() {
+        let leaves = leaves(5);
+        let root = merkle_root(&leaves).unwrap();
+        let proof = merkle_proof(&leaves, 1).unwrap();
+
+        let mut tampered = proof.clone();
+        tampered[0][0] ^= 1;
+        assert!(!verify_proof(&root, &leaves[1], &tampered));
+
+        // proof of another leaf
+        assert!(!verify_proof(&root, &leaves[2], &proof));
+        // truncated proof
+        assert!(!verify_proof(&root, &leaves[1], &proof[..proof.len() - 1]));
+        // leaf not in the tree
+        assert!(!verify_proof(&root, &hash_leaf(&[42]), &proof));
+    }
+
+    #[test]
+    fn inner_node_cannot_be_presented_as_leaf() {
+        let leaves = leaves(4);
+        let root = merkle_root(&leaves).unwrap();
+        let left = hash_node(&leaves[0], &leaves[1]);
+        let right = hash_node(&leaves[2], &leaves[3]);
+        // inner node itself is on the path to root
+        assert!(verify_proof(&root, &left, &[right]));
+
+        // but leaf data that concatenates it's children hashes to a different value
+        let (first, second) = if leaves[0] <= leaves[1] {
+            (leaves[0], leaves[1])
+        } else {
+            (leaves[1], leaves[0])
+        };
+        let forged = hash_leaf(&[first, second].concat());
+        assert_ne!(forged, left);
+        assert!(!verify_proof(&root, &forged, &[right]));
+    }
+
+    #[test]
+    fn node_hash_does_not_depend_on_child_order() {
+        let leaves = leaves(2);
+        assert_eq!(
+            hash_node(&leaves[0], &leaves[1]),
+            hash_node(&leaves[1], &leaves[0])
+        );
+    }
+}
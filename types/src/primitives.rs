@@ -4,7 +4,7 @@ use frame_support::{
     BoundedVec,
 };
 use scale_info::TypeInfo;
-use sp_std::fmt::Debug;
+use sp_std::{fmt::Debug, vec::Vec};
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -34,11 +34,142 @@ impl<Bound> Scaler<Bound> {
     }
 }
 
+/// number of bytes in every `UniqId`
+pub const UNIQ_ID_LENGTH: usize = 32;
+
 /// type to uniquely represent any item
-/// this better to be a had bytes
-#[derive(Decode, Encode, TypeInfo, Debug, Eq, PartialEq, Clone, MaxEncodedLen)]
-#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
-pub struct UniqId {}
+/// this is the content-addressed hash bytes of the item
+#[derive(
+    Decode,
+    Encode,
+    TypeInfo,
+    Debug,
+    Eq,
+    PartialEq,
+    Clone,
+    Copy,
+    Hash,
+    PartialOrd,
+    Ord,
+    MaxEncodedLen,
+)]
+pub struct UniqId([u8; UNIQ_ID_LENGTH]);
+
+impl UniqId {
+    pub const fn new(bytes: [u8; UNIQ_ID_LENGTH]) -> Self {
+        Self(bytes)
+    }
+
+    /// derive the id from blake2_256 hash of client supplied seed
+    pub fn from_seed(seed: &[u8]) -> Self {
+        Self(sp_io::hashing::blake2_256(seed))
+    }
+
+    pub fn get(&self) -> &[u8; UNIQ_ID_LENGTH] {
+        &self.0
+    }
+
+    pub fn inner(self) -> [u8; UNIQ_ID_LENGTH] {
+        self.0
+    }
+}
+
+impl From<[u8; UNIQ_ID_LENGTH]> for UniqId {
+    fn from(bytes: [u8; UNIQ_ID_LENGTH]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl AsRef<[u8]> for UniqId {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// lowercase hex representation of the id prefixed with `0x`
+impl sp_std::fmt::Display for UniqId {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
+        f.write_str("0x")?;
+        for byte in self.0.iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error while parsing `UniqId` from hex string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UniqIdParseError {
+    /// string does not have exactly `UNIQ_ID_LENGTH` hex encoded bytes
+    InvalidLength,
+    /// string contains non-hex character
+    InvalidCharacter,
+}
+
+impl sp_std::fmt::Display for UniqIdParseError {
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
+        match self {
+            UniqIdParseError::InvalidLength => f.write_str("invalid length of UniqId"),
+            UniqIdParseError::InvalidCharacter => f.write_str("invalid hex character in UniqId"),
+        }
+    }
+}
+
+/// parse from hex string with or without `0x` prefix
+impl sp_std::str::FromStr for UniqId {
+    type Err = UniqIdParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+        if hex.len() != UNIQ_ID_LENGTH * 2 {
+            return Err(UniqIdParseError::InvalidLength);
+        }
+
+        let nibble = |c: u8| -> Result<u8, UniqIdParseError> {
+            match c {
+                b'0'..=b'9' => Ok(c - b'0'),
+                b'a'..=b'f' => Ok(c - b'a' + 10),
+                b'A'..=b'F' => Ok(c - b'A' + 10),
+                _ => Err(UniqIdParseError::InvalidCharacter),
+            }
+        };
+
+        let mut bytes = [0_u8; UNIQ_ID_LENGTH];
+        for (at, pair) in hex.chunks(2).enumerate() {
+            bytes[at] = (nibble(pair[0])? << 4) | nibble(pair[1])?;
+        }
+
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Serialize for UniqId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> Deserialize<'de> for UniqId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        hex.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Kind of entity a chain assigned `UniqId` is generated for
+#[derive(Decode, Encode, TypeInfo, Debug, Eq, PartialEq, Clone, Copy, MaxEncodedLen)]
+pub enum IdEntityKind {
+    University,
+    Professor,
+    Student,
+    Application,
+    Validator,
+    Offer,
+    Exam,
+    Certificate,
+}
 
 /// Structure to represent the IPFS link
 #[derive(Decode, Encode, TypeInfo, Clone, MaxEncodedLen, Debug)]
@@ -95,7 +226,92 @@ impl<S: Debug + Get<u32>> IpfsLink<S> {
     }
 }
 
+/// Single page of a paginated query
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+pub struct Page<Key, Item> {
+    pub items: Vec<(Key, Item)>,
+    /// key to pass as start of the next page
+    /// `None` if this is the last page
+    pub next: Option<Key>,
+}
+
 // common types alias
 pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type StdIpfsLink = IpfsLink<IpfsLinkLength>; // reasonable bounded vec to cover up a ipfs link
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEX: &str = "0x000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    fn sample_id() -> UniqId {
+        let mut bytes = [0_u8; UNIQ_ID_LENGTH];
+        for (at, byte) in bytes.iter_mut().enumerate() {
+            *byte = at as u8;
+        }
+        UniqId::new(bytes)
+    }
+
+    #[test]
+    fn uniq_id_display_is_prefixed_lowercase_hex() {
+        assert_eq!(sample_id().to_string(), HEX);
+    }
+
+    #[test]
+    fn uniq_id_parse_display_round_trip() {
+        let id = sample_id();
+        assert_eq!(id.to_string().parse::<UniqId>(), Ok(id));
+        // prefix is optional and case does not matter
+        assert_eq!(HEX[2..].parse::<UniqId>(), Ok(id));
+        assert_eq!(
+            HEX.to_uppercase().replacen("0X", "0x", 1).parse::<UniqId>(),
+            Ok(id)
+        );
+    }
+
+    #[test]
+    fn uniq_id_serde_round_trip() {
+        let id = sample_id();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{}\"", HEX));
+        assert_eq!(serde_json::from_str::<UniqId>(&json).unwrap(), id);
+    }
+
+    #[test]
+    fn uniq_id_rejects_bad_length() {
+        assert_eq!("".parse::<UniqId>(), Err(UniqIdParseError::InvalidLength));
+        assert_eq!("0x".parse::<UniqId>(), Err(UniqIdParseError::InvalidLength));
+        assert_eq!(
+            HEX[..HEX.len() - 2].parse::<UniqId>(),
+            Err(UniqIdParseError::InvalidLength)
+        );
+        assert_eq!(
+            format!("{}00", HEX).parse::<UniqId>(),
+            Err(UniqIdParseError::InvalidLength)
+        );
+        // odd number of hex characters
+        assert_eq!(
+            HEX[..HEX.len() - 1].parse::<UniqId>(),
+            Err(UniqIdParseError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn uniq_id_rejects_bad_hex() {
+        let bad = format!("{}zz", &HEX[..HEX.len() - 2]);
+        assert_eq!(
+            bad.parse::<UniqId>(),
+            Err(UniqIdParseError::InvalidCharacter)
+        );
+        // multi byte characters are not hex either
+        let bad = format!("{}é", &HEX[..HEX.len() - 2]);
+        assert_eq!(
+            bad.parse::<UniqId>(),
+            Err(UniqIdParseError::InvalidCharacter)
+        );
+        assert!(serde_json::from_str::<UniqId>("\"0xnothex\"").is_err());
+    }
+}
@@ -11,7 +11,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::fmt::Debug;
 
-use crate::primitives::StdIpfsLink;
+use crate::{primitives::StdIpfsLink, university::FacultyId};
 
 /// Required paramater to register this professor
 #[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug)]
@@ -21,15 +21,81 @@ pub struct NewProfessorParam {
 
 /// Information of a professor
 #[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub struct ProfessorInfo<AccountId> {
     pub professor: AccountId,
     pub info: StdIpfsLink,
 }
 
+/// Required parameter to make an offer to professor
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct NewOfferParam<BlockNumber> {
+    pub professor: ProfessorId,
+    pub university: crate::university::UniversityId,
+    pub contract_file: StdIpfsLink,
+    /// role professor will hold once offer is accepted
+    pub role: AffiliationRole,
+    /// faculty professor will join once offer is accepted
+    pub faculty: Option<FacultyId>,
+    /// number of blocks professor have to respond to the offer
+    pub validity: BlockNumber,
+}
+
 /// Offer Info
 #[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
-pub struct OfferInfo {
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct OfferInfo<BlockNumber> {
     pub professor: ProfessorId,
     pub university: crate::university::UniversityId,
     pub contract_file: StdIpfsLink,
+    /// role professor will hold once offer is accepted
+    pub role: AffiliationRole,
+    /// faculty professor will join once offer is accepted
+    pub faculty: Option<FacultyId>,
+    /// offer cannot be accepted from this block onwards
+    pub expires_on: BlockNumber,
+}
+
+/// Position held by professor in an university
+#[derive(Decode, Encode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub enum AffiliationRole {
+    Lecturer,
+    AssistantProfessor,
+    AssociateProfessor,
+    Professor,
+    Visiting,
+}
+
+/// Affiliation of professor with an university
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub struct Affiliation<BlockNumber> {
+    pub role: AffiliationRole,
+    /// block affiliation started on
+    pub start: BlockNumber,
+    /// block affiliation ended on
+    /// `None` while professor is still affiliated
+    pub end: Option<BlockNumber>,
+    /// faculty professor is part of
+    pub faculty: Option<FacultyId>,
+    /// signed contract of this affiliation
+    pub contract: StdIpfsLink,
+}
+
+impl<BlockNumber> Affiliation<BlockNumber> {
+    pub fn is_active(&self) -> bool {
+        self.end.is_none()
+    }
+}
+
+/// Terms of employment proposed by university to professor
+/// proposed terms are made to professor as an offer
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct NewContractParam<BlockNumber> {
+    pub role: AffiliationRole,
+    pub faculty: Option<FacultyId>,
+    pub contract: StdIpfsLink,
+    /// number of blocks professor have to accept the offer
+    pub validity: BlockNumber,
 }
@@ -1,13 +1,28 @@
 // Identifier type to uniquely represent a scholarship
 pub type ScholarshipId = crate::primitives::UniqId;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::fmt::Debug;
 
+use crate::StdIpfsLink;
+
 /// Required paramater to register this scholarship
 #[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug)]
-pub struct NewScholarshipParam {}
+pub struct NewScholarshipParam {
+    /// no. of student to provide this grant to
+    pub max_grantees: u32,
+    /// ipfs link to scholarship details
+    pub details: StdIpfsLink,
+}
 
 /// Information of a scholarship
-pub struct Scholarship {}
+#[derive(Decode, Encode, TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen)]
+pub struct Scholarship<AccountId> {
+    /// who is granting this scholarship
+    pub host: AccountId,
+    pub max_grantees: u32,
+    pub details: StdIpfsLink,
+}
+
+pub type ScholarshipInfoFor<T> = Scholarship<crate::AccountIdOf<T>>;
@@ -4,14 +4,18 @@ pub type StudentId = crate::primitives::UniqId;
 pub type ApplicationId = crate::primitives::UniqId;
 
 use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec};
 use scale_info::TypeInfo;
 use sp_std::fmt::Debug;
 
-use crate::StdIpfsLink;
+use crate::{university::UniversityId, StdIpfsLink};
 
 /// parameter required to register as student
 #[derive(Decode, Encode, TypeInfo, Clone, Debug, PartialEq, Eq, MaxEncodedLen)]
-pub struct NewStudentParam {}
+pub struct NewStudentParam {
+    /// ipfs link to the profile of student
+    pub profile: StdIpfsLink,
+}
 
 #[derive(Decode, Encode, TypeInfo, Clone, Debug, PartialEq, Eq, MaxEncodedLen)]
 pub struct NewApplicationParam {
@@ -22,12 +26,38 @@ pub struct NewApplicationParam {
 #[derive(Decode, Encode, TypeInfo, Clone, Debug, PartialEq, Eq, MaxEncodedLen)]
 pub struct Application<AccountId> {
     pub applicant: AccountId,
+    /// student profile of the applicant
+    pub student: StudentId,
     pub university: crate::university::UniversityId,
     pub application: StdIpfsLink,
 }
 
+/// maximum number of universities a student can be enrolled in
+pub type MaxEnrollments = ConstU32<16>;
+
+/// universities a student is enrolled in
+pub type EnrollmentList = BoundedVec<UniversityId, MaxEnrollments>;
+
+#[derive(Decode, Encode, TypeInfo, Clone, Debug, PartialEq, Eq, MaxEncodedLen)]
+pub enum StudentStatus {
+    /// Student is registered but not enrolled in any university
+    Registered,
+    /// Student is enrolled in at least one university
+    Active,
+    /// Student have completed the studies and is not enrolled anywhere else
+    Graduated,
+}
+
 /// information stored of a student
 #[derive(Decode, Encode, TypeInfo, Clone, Debug, PartialEq, Eq, MaxEncodedLen)]
-pub struct Student {}
+pub struct Student<AccountId> {
+    /// account controlling this student profile
+    pub account: AccountId,
+    /// ipfs link to the profile of student
+    pub profile: StdIpfsLink,
+    pub enrollments: EnrollmentList,
+    pub status: StudentStatus,
+}
 
 pub type ApplicationInfoFor<T> = Application<crate::AccountIdOf<T>>;
+pub type StudentInfoFor<T> = Student<crate::AccountIdOf<T>>;
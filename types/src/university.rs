@@ -1,11 +1,17 @@
 /// Identifier type to uniquely represent a university
 pub type UniversityId = crate::primitives::UniqId;
 
+/// Identifier type to uniquely represent a programme offered by university
+pub type ProgrammeId = crate::primitives::UniqId;
+
+/// Identifier type to uniquely represent a faculty of university
+pub type FacultyId = crate::primitives::UniqId;
+
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::fmt::Debug;
 
-use crate::primitives::StdIpfsLink as IpfsLink;
+use crate::{intake::IntakeId, primitives::StdIpfsLink as IpfsLink, student::ApplicationId};
 
 #[derive(Decode, Encode, TypeInfo, Clone, Debug, Eq, PartialEq)]
 pub struct NewUniversityParam<AccountId> {
@@ -15,6 +21,7 @@ pub struct NewUniversityParam<AccountId> {
 
 /// information stored of a university
 #[derive(Decode, Encode, TypeInfo, Clone, MaxEncodedLen, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub struct University<AccountId> {
     /// who own the right to modify this university
     // this will most probably be the multi-signature AccountId
@@ -22,3 +29,24 @@ pub struct University<AccountId> {
     pub admin: AccountId,
     pub permanent_info: IpfsLink,
 }
+
+/// how a student got enrolled in university
+#[derive(Decode, Encode, TypeInfo, Clone, MaxEncodedLen, Debug, PartialEq, Eq)]
+pub enum EnrollmentSource {
+    /// student confirmed the offer made on this intake
+    Intake(IntakeId<UniversityId>),
+    /// university accepted the enrollment application sent by student
+    Application(ApplicationId),
+}
+
+/// enrollment record of a student in a university
+#[derive(Decode, Encode, TypeInfo, Clone, MaxEncodedLen, Debug, PartialEq, Eq)]
+pub struct Enrollment<BlockNumber> {
+    /// block number when student got enrolled
+    pub enrolled_on: BlockNumber,
+    pub source: EnrollmentSource,
+    /// programme student is enrolled in, if any
+    pub programme: Option<ProgrammeId>,
+    /// block number when student graduated, None while still studying
+    pub graduated_on: Option<BlockNumber>,
+}
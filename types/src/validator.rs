@@ -1,38 +1,119 @@
 pub type ValidatorId = crate::primitives::UniqId;
 
-use crate::StdIpfsLink;
+use crate::{university::FacultyId, StdIpfsLink};
 use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::Get, BoundedVec};
 use scale_info::TypeInfo;
 
+const MAX_VALIDATION_SCOPE: u32 = 16;
+
+/// maximum number of faculties a single validation can be scoped to
+#[derive(Decode, Encode, TypeInfo, Debug, Eq, PartialEq, Clone, MaxEncodedLen)]
+pub struct MaxValidationScope;
+impl<T: From<u32>> Get<T> for MaxValidationScope {
+    fn get() -> T {
+        MAX_VALIDATION_SCOPE.into()
+    }
+}
+
+/// faculties a validation is limited to
+pub type ValidationScope = BoundedVec<FacultyId, MaxValidationScope>;
+
+/// Level of trust placed on the validator
+#[derive(Decode, Encode, TypeInfo, Debug, Eq, PartialEq, Clone, Copy, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub enum ValidatorTier {
+    /// set by governance, approves accredited validators
+    Root,
+    /// approved by the root validator
+    Accredited,
+    /// self registered against a deposit
+    Open,
+}
+
 /// Information of the individual validator
 #[derive(Decode, Encode, TypeInfo, Debug, Eq, PartialEq, Clone, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 pub struct ValidatorInfo<AccountId> {
     pub document: StdIpfsLink,
     pub admin: AccountId,
+    pub tier: ValidatorTier,
+}
+
+/// Level of accreditation granted by validator
+#[derive(Decode, Encode, TypeInfo, Debug, Eq, PartialEq, Clone, Copy, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+pub enum AccreditationLevel {
+    /// granted for limited time until full review is done
+    Provisional,
+    /// granted with conditions to be met before renewal
+    Conditional,
+    Full,
+}
+
+/// Terms of validation granted by validator
+#[derive(Decode, Encode, TypeInfo, Debug, Eq, PartialEq, Clone, MaxEncodedLen)]
+pub struct ValidationTerms<BlockNumber> {
+    /// ipfs link to the evidence validation is based on
+    pub evidence: StdIpfsLink,
+    pub level: AccreditationLevel,
+    /// number of blocks validation stays valid for
+    pub validity: BlockNumber,
+    /// faculties validation is limited to
+    /// `None` if whole entity is validated
+    pub scope: Option<ValidationScope>,
 }
 
 /// Information of the validated university
 #[derive(Decode, Encode, TypeInfo, Debug, Eq, PartialEq, Clone, MaxEncodedLen)]
-pub struct ValidateUniversityParam<UniversityId> {
+pub struct ValidateUniversityParam<UniversityId, BlockNumber> {
     pub university: UniversityId,
+    pub terms: ValidationTerms<BlockNumber>,
 }
 
 /// Information of the validated professor
 #[derive(Decode, Encode, TypeInfo, Debug, Eq, PartialEq, Clone, MaxEncodedLen)]
-pub struct ValidateProfessorParam<ProfessorId> {
+pub struct ValidateProfessorParam<ProfessorId, BlockNumber> {
     pub professor: ProfessorId,
+    pub terms: ValidationTerms<BlockNumber>,
 }
 
-/// Information of the validated university
+/// Validation record kept by validator for an entity
 #[derive(Decode, Encode, TypeInfo, Debug, Eq, PartialEq, Clone, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
-pub struct ValidatedUniversityInfo {}
+pub struct ValidationRecord<BlockNumber> {
+    /// ipfs link to the evidence validation is based on
+    pub evidence: StdIpfsLink,
+    pub level: AccreditationLevel,
+    /// block validation was granted or last renewed on
+    pub valid_from: BlockNumber,
+    /// validation expires on this block
+    pub valid_until: BlockNumber,
+    /// faculties validation is limited to
+    /// `None` if whole entity is validated
+    pub scope: Option<ValidationScope>,
+}
+
+impl<BlockNumber: PartialOrd> ValidationRecord<BlockNumber> {
+    /// whether validation is in effect at block `now`
+    pub fn is_valid_at(&self, now: &BlockNumber) -> bool {
+        &self.valid_from <= now && now < &self.valid_until
+    }
+}
+
+/// Information of the validated university
+pub type ValidatedUniversityInfo<BlockNumber> = ValidationRecord<BlockNumber>;
 
 /// Information of the validated professor
+pub type ValidatedProfessorInfo<BlockNumber> = ValidationRecord<BlockNumber>;
+
+/// Entity validation is granted to
 #[derive(Decode, Encode, TypeInfo, Debug, Eq, PartialEq, Clone, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
-pub struct ValidatedProfessorInfo {}
+pub enum ValidationTarget<UniversityId, ProfessorId> {
+    University(UniversityId),
+    Professor(ProfessorId),
+}
 
 /// Information required to register a new validator
 #[derive(Decode, Encode, TypeInfo, Debug, Eq, PartialEq, Clone, MaxEncodedLen)]